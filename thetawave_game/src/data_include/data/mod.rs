@@ -12,6 +12,12 @@ pub struct IncludeData<'a> {
     pub spawner: &'a [u8],
     pub defense: &'a [u8],
     pub drop_tables: &'a [u8],
+    pub factions: &'a [u8],
+    pub secondary_weapons: &'a [u8],
+    pub stat_clamps: &'a [u8],
+    pub buffs: &'a [u8],
+    pub synergies: &'a [u8],
+    pub unlocks: &'a [u8],
 }
 
 pub fn load_include_data() -> IncludeData<'static> {
@@ -29,5 +35,11 @@ pub fn load_include_data() -> IncludeData<'static> {
         spawner: include_bytes!("spawner.ron"),
         defense: include_bytes!("defense.ron"),
         drop_tables: include_bytes!("drop_tables.ron"),
+        factions: include_bytes!("factions.ron"),
+        secondary_weapons: include_bytes!("secondary_weapons.ron"),
+        stat_clamps: include_bytes!("stat_clamps.ron"),
+        buffs: include_bytes!("buffs.ron"),
+        synergies: include_bytes!("synergies.ron"),
+        unlocks: include_bytes!("unlocks.ron"),
     }
 }
@@ -21,16 +21,17 @@ use amethyst::{
 mod data_include;
 mod states;
 
-use states::MainGameState;
+use states::CharacterSelectState;
 use thetawave_lib::{
     resources::{
-        DebugLinesConfig, DefenseResource, DropTablesResource, GameParametersResource,
-        PhaseManagerResource, PlayersResource, SoundsConfig, SpriteSheetsConfig, StoreResource,
+        DebugLinesConfig, DefenseResource, DropTablesResource, FactionsResource,
+        GameParametersResource, PhaseManagerResource, PlayersResource, SaveProfileResource,
+        SecondaryWeaponsResource, SoundsConfig, SpriteSheetsConfig, StoreResource, UnlocksResource,
     },
     spawn::resources::SpawnerResource,
     spawnable::resources::{
-        ConsumableModifiersResource, ConsumablesResource, EffectsResource, ItemModifiersResource,
-        ItemsResource, MobsResource,
+        BuffsResource, ConsumableModifiersResource, ConsumablesResource, EffectsResource,
+        ItemModifiersResource, ItemsResource, MobsResource, StatClampsResource, SynergiesResource,
     },
 };
 
@@ -73,6 +74,12 @@ fn main() -> amethyst::Result<()> {
         spawner,
         defense,
         drop_tables,
+        factions,
+        secondary_weapons,
+        stat_clamps,
+        buffs,
+        synergies,
+        unlocks,
     } = load_include_data();
 
     let items =
@@ -102,6 +109,19 @@ fn main() -> amethyst::Result<()> {
         .expect("failed to load data file: defense.ron");
     let drop_tables = <DropTablesResource as Config>::load_bytes(drop_tables)
         .expect("failed to load data file: drop_tables.ron");
+    let factions = <FactionsResource as Config>::load_bytes(factions)
+        .expect("failed to load data file: factions.ron");
+    let secondary_weapons = <SecondaryWeaponsResource as Config>::load_bytes(secondary_weapons)
+        .expect("failed to load data file: secondary_weapons.ron");
+    let stat_clamps = <StatClampsResource as Config>::load_bytes(stat_clamps)
+        .expect("failed to load data file: stat_clamps.ron");
+    let buffs =
+        <BuffsResource as Config>::load_bytes(buffs).expect("failed to load data file: buffs.ron");
+    let synergies = <SynergiesResource as Config>::load_bytes(synergies)
+        .expect("failed to load data file: synergies.ron");
+    let unlocks = <UnlocksResource as Config>::load_bytes(unlocks)
+        .expect("failed to load data file: unlocks.ron");
+    let save_profile = SaveProfileResource::load_profile();
 
     let game_data = GameDataBuilder::default()
         .with_system_desc(GltfSceneLoaderSystemDesc::default(), "gltf_system", &[])
@@ -121,7 +141,7 @@ fn main() -> amethyst::Result<()> {
                 .with_plugin(RenderDebugLines::default()),
         )?;
 
-    let mut game = Application::build(app_root.join("assets"), MainGameState::default())?
+    let mut game = Application::build(app_root.join("assets"), CharacterSelectState::default())?
         .with_resource(items)
         .with_resource(item_modifiers)
         .with_resource(consumable_modifiers)
@@ -138,6 +158,13 @@ fn main() -> amethyst::Result<()> {
         .with_resource(spawner)
         .with_resource(defense)
         .with_resource(drop_tables)
+        .with_resource(factions)
+        .with_resource(secondary_weapons)
+        .with_resource(stat_clamps)
+        .with_resource(buffs)
+        .with_resource(synergies)
+        .with_resource(unlocks)
+        .with_resource(save_profile)
         .build(game_data)?;
 
     game.run();
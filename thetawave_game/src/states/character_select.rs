@@ -0,0 +1,176 @@
+use amethyst::{
+    assets::Loader,
+    ecs::prelude::Entity,
+    input::{is_key_down, VirtualKeyCode},
+    prelude::*,
+    ui::{Anchor, LineMode, TtfFormat, UiText, UiTransform},
+};
+use thetawave_lib::resources::{PlayersResource, SaveProfileResource, Unlock, UnlocksResource};
+
+use crate::states::{MainGameState, MetaShopState};
+
+/// Maximum number of local players
+const MAX_PLAYERS: usize = 2;
+
+/// Lets each player choose a character from players.ron before starting the game
+#[derive(Default)]
+pub struct CharacterSelectState {
+    /// Names of the selectable characters that are unlocked
+    characters: Vec<String>,
+    /// Index of the highlighted character
+    selected: usize,
+    /// Number of players joining the game
+    player_count: usize,
+    /// Characters confirmed by the players so far
+    chosen: Vec<String>,
+    /// Text displaying the highlighted character
+    display: Option<Entity>,
+}
+
+impl SimpleState for CharacterSelectState {
+    fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        let world = data.world;
+
+        self.load_characters(world);
+        self.player_count = 1;
+        self.chosen.clear();
+
+        let font_handle = world.read_resource::<Loader>().load(
+            "font/SpaceMadness.ttf",
+            TtfFormat,
+            (),
+            &world.read_resource(),
+        );
+        let ui_transform = UiTransform::new(
+            String::from("character_select_text"),
+            Anchor::Middle,
+            Anchor::Middle,
+            0.0,
+            0.0,
+            0.0,
+            400.0,
+            200.0,
+        );
+        let ui_text = UiText::new(
+            font_handle,
+            String::new(),
+            [1.0, 1.0, 1.0, 1.0],
+            25.0,
+            LineMode::Wrap,
+            Anchor::Middle,
+        );
+
+        self.display = Some(
+            world
+                .create_entity()
+                .with(ui_transform)
+                .with(ui_text)
+                .build(),
+        );
+        self.update_display(world);
+    }
+
+    fn on_pause(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        // the meta shop shows its own text
+        if let Some(display) = self.display {
+            if let Some(text) = data.world.write_storage::<UiText>().get_mut(display) {
+                text.text.clear();
+            }
+        }
+    }
+
+    fn on_resume(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        // characters may have been unlocked in the meta shop
+        self.load_characters(data.world);
+        self.update_display(data.world);
+    }
+
+    fn on_stop(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        if let Some(display) = self.display.take() {
+            data.world
+                .delete_entity(display)
+                .expect("Failed to remove character select text.");
+        }
+    }
+
+    fn handle_event(
+        &mut self,
+        data: StateData<'_, GameData<'_, '_>>,
+        event: StateEvent,
+    ) -> SimpleTrans {
+        if let StateEvent::Window(event) = &event {
+            if is_key_down(&event, VirtualKeyCode::Tab) {
+                return Trans::Push(Box::new(MetaShopState::default()));
+            }
+
+            if self.characters.is_empty() {
+                return Trans::None;
+            }
+
+            if is_key_down(&event, VirtualKeyCode::Left) || is_key_down(&event, VirtualKeyCode::A) {
+                self.selected = (self.selected + self.characters.len() - 1) % self.characters.len();
+                self.update_display(data.world);
+            } else if is_key_down(&event, VirtualKeyCode::Right)
+                || is_key_down(&event, VirtualKeyCode::D)
+            {
+                self.selected = (self.selected + 1) % self.characters.len();
+                self.update_display(data.world);
+            } else if (is_key_down(&event, VirtualKeyCode::Up)
+                || is_key_down(&event, VirtualKeyCode::Down))
+                && self.chosen.is_empty()
+            {
+                // player count can only change before the first character is chosen
+                self.player_count = self.player_count % MAX_PLAYERS + 1;
+                self.update_display(data.world);
+            } else if is_key_down(&event, VirtualKeyCode::Return)
+                || is_key_down(&event, VirtualKeyCode::Space)
+            {
+                self.chosen.push(self.characters[self.selected].clone());
+                if self.chosen.len() >= self.player_count {
+                    return Trans::Switch(Box::new(MainGameState::new(self.chosen.clone())));
+                }
+                self.update_display(data.world);
+            }
+        }
+        Trans::None
+    }
+}
+
+impl CharacterSelectState {
+    /// Collect the characters that are not locked or have been bought in the meta shop
+    fn load_characters(&mut self, world: &World) {
+        let unlocks = world.read_resource::<UnlocksResource>();
+        let save_profile = world.read_resource::<SaveProfileResource>();
+
+        self.characters = world
+            .read_resource::<PlayersResource>()
+            .keys()
+            .filter(|character| {
+                unlocks.is_available(&Unlock::Character((*character).clone()), &save_profile)
+            })
+            .cloned()
+            .collect();
+        self.characters.sort();
+        self.selected = self.selected.min(self.characters.len().saturating_sub(1));
+    }
+
+    /// Show the choosing player and the name and description of the highlighted character
+    fn update_display(&self, world: &mut World) {
+        if let (Some(display), Some(character)) = (self.display, self.characters.get(self.selected))
+        {
+            let description = world.read_resource::<PlayersResource>()[character]
+                .description
+                .clone();
+
+            if let Some(text) = world.write_storage::<UiText>().get_mut(display) {
+                text.text = format!(
+                    "{} player(s) - player {}\n< {} >\n{}\n\ntab: unlocks",
+                    self.player_count,
+                    self.chosen.len() + 1,
+                    character,
+                    description
+                );
+            }
+        }
+    }
+}
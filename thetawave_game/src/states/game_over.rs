@@ -0,0 +1,83 @@
+use amethyst::{
+    assets::Loader,
+    ecs::prelude::Entity,
+    input::{is_key_down, VirtualKeyCode},
+    prelude::*,
+    ui::{Anchor, LineMode, TtfFormat, UiText, UiTransform},
+};
+use thetawave_lib::resources::{PhaseManagerResource, SaveProfileResource};
+
+/// Ends the run once every player is out of lives
+#[derive(Default)]
+pub struct GameOverState {
+    /// Text displaying the phase the run reached
+    display: Option<Entity>,
+}
+
+impl SimpleState for GameOverState {
+    fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        let world = data.world;
+
+        let text = format!(
+            "game over\n\nphase reached: {}  best phase: {}\n\npress enter to quit",
+            world.read_resource::<PhaseManagerResource>().phase_idx + 1,
+            world.read_resource::<SaveProfileResource>().best_phase
+        );
+
+        let font_handle = world.read_resource::<Loader>().load(
+            "font/SpaceMadness.ttf",
+            TtfFormat,
+            (),
+            &world.read_resource(),
+        );
+        let ui_transform = UiTransform::new(
+            String::from("game_over_text"),
+            Anchor::Middle,
+            Anchor::Middle,
+            0.0,
+            0.0,
+            0.0,
+            400.0,
+            200.0,
+        );
+        let ui_text = UiText::new(
+            font_handle,
+            text,
+            [1.0, 1.0, 1.0, 1.0],
+            25.0,
+            LineMode::Wrap,
+            Anchor::Middle,
+        );
+
+        self.display = Some(
+            world
+                .create_entity()
+                .with(ui_transform)
+                .with(ui_text)
+                .build(),
+        );
+    }
+
+    fn on_stop(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        if let Some(display) = self.display.take() {
+            data.world
+                .delete_entity(display)
+                .expect("Failed to remove game over text.");
+        }
+    }
+
+    fn handle_event(
+        &mut self,
+        _data: StateData<'_, GameData<'_, '_>>,
+        event: StateEvent,
+    ) -> SimpleTrans {
+        if let StateEvent::Window(event) = &event {
+            if is_key_down(&event, VirtualKeyCode::Return)
+                || is_key_down(&event, VirtualKeyCode::Escape)
+            {
+                return Trans::Quit;
+            }
+        }
+        Trans::None
+    }
+}
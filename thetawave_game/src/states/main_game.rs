@@ -1,7 +1,7 @@
 use amethyst::{
     assets::{AssetStorage, Loader},
     core::transform::Transform,
-    ecs::prelude::{Dispatcher, DispatcherBuilder, Entity},
+    ecs::prelude::{Dispatcher, DispatcherBuilder, Entity, Join},
     input::{is_key_down, VirtualKeyCode},
     prelude::*,
     renderer::debug_drawing::{DebugLines, DebugLinesParams},
@@ -12,54 +12,79 @@ use amethyst::{
 use std::{collections::HashMap, f32::consts::FRAC_PI_3};
 use thetawave_lib::{
     audio::initialize_audio,
+    components::PlayerComponent,
     constants::{
         ARENA_HEIGHT, ARENA_MAX_X, ARENA_MIN_X, ARENA_MIN_Y, ARENA_WIDTH, CAMERA_X, CAMERA_Y,
-        CAMERA_Z,
+        CAMERA_Z, ITEM_PRICES_TOP_Y,
     },
-    entities::{initialize_arena_barriers, initialize_spaceship},
+    entities::{initialize_arena_barriers, initialize_spaceship, ConsumableType},
     motion::systems::{
-        BlastMotion2DSystem, CollisionDetectionSystem, CollisionHandlerSystem,
-        ConsumableMotion2DSystem, ItemMotion2DSystem, MobArenaBorderCollisionSystem,
-        MobBlastCollisionSystem, MobMobCollisionSystem, MobMotion2DSystem,
-        MobPlayerCollisionSystem, MobTargetSystem, Motion2DSystem,
+        BlastArenaBorderCollisionSystem, BlastMotion2DSystem, CollisionDetectionSystem,
+        CollisionHandlerSystem, ConsumableMotion2DSystem, ItemMotion2DSystem,
+        MobArenaBorderCollisionSystem, MobBlastCollisionSystem, MobMobCollisionSystem,
+        MobMotion2DSystem, MobPlayerCollisionSystem, Motion2DSystem,
         PlayerArenaBorderCollisionSystem, PlayerBlastCollisionSystem,
         PlayerConsumableCollisionSystem, PlayerItemCollisionSystem, PlayerMobCollisionSystem,
-        PlayerMotion2DSystem,
+        PlayerMotion2DSystem, TargetSystem,
+    },
+    resources::{
+        DebugLinesConfig, SaveProfileResource, SpriteSheetsConfig, SpriteSheetsResource,
+        StoreResource, UnlocksResource,
     },
-    resources::{DebugLinesConfig, SpriteSheetsConfig, SpriteSheetsResource},
     spawn::systems::{
-        AutoSpawnerSystem, DespawnAtBorderSystem, DespawnTimeLimitSystem, SpawnerSystem,
+        AutoSpawnerSystem, DespawnAtBorderSystem, DespawnTimeLimitSystem,
+        HealthThresholdSpawnerSystem, SpawnerSystem, TimedMobSpawnerSystem,
     },
     spawnable::{
+        resources::ConsumablesResource,
         systems::ModifiersSystem,
         systems::{MobBehaviorSystem, MobDestroyedSystem},
     },
     systems,
     visual::{
         entities::{
-            initialize_background, initialize_planet, initialize_side_panels,
-            initialize_status_bars, initialize_store_icons,
+            initialize_announcement, initialize_background, initialize_buff_icons,
+            initialize_planet, initialize_side_panels, initialize_status_bars,
+            initialize_store_icons,
         },
         systems::{
-            AnimationSystem, FadeSystem, PlanetsSystem, StatTrackerSystem, StatusBarSystem,
-            TrackedStats,
+            AnimationSystem, AnnouncementSystem, BuffIconSystem, FadeSystem, PlanetsSystem,
+            StatTrackerSystem, StatusBarSystem, TrackedStats,
         },
     },
-    weapons::systems::{AutoFireSystem, ManualBlasterSystem},
+    weapons::systems::{AutoFireSystem, BeamSystem, ManualBlasterSystem, SecondaryWeaponSystem},
 };
 
-use crate::states::PausedState;
+use crate::states::{GameOverState, PausedState, ShopState};
+
+/// Index of the lives icon after the character sprites
+const LIVES_SPRITE_INDEX: usize = 4;
 
 pub struct MainGameState {
+    characters: Vec<String>,
     is_paused: bool,
+    /// Whether the game is paused by the shop between phases
+    is_shopping: bool,
     pause_display: Option<Entity>,
     dispatcher: Dispatcher<'static, 'static>,
 }
 
+impl MainGameState {
+    /// Create the main game state with a player for each of the given characters
+    pub fn new(characters: Vec<String>) -> Self {
+        MainGameState {
+            characters,
+            ..Default::default()
+        }
+    }
+}
+
 impl Default for MainGameState {
     fn default() -> Self {
         MainGameState {
+            characters: vec!["juggernaut".to_string()],
             is_paused: false,
+            is_shopping: false,
             pause_display: None,
             dispatcher: DispatcherBuilder::new()
                 .with(AnimationSystem, "animation_system", &[])
@@ -70,8 +95,23 @@ impl Default for MainGameState {
                 .with(SpawnerSystem, "spawner_system", &[])
                 .with(DespawnTimeLimitSystem, "timelimit_system", &[])
                 .with(Motion2DSystem, "motion_2d_system", &[])
-                .with(MobTargetSystem, "mob_target_system", &[])
+                .with(
+                    systems::PhaseEnemyTargetSystem,
+                    "phase_enemy_target_system",
+                    &[],
+                )
+                .with(
+                    TargetSystem,
+                    "target_system",
+                    &["phase_enemy_target_system"],
+                )
                 .with(AutoSpawnerSystem, "auto_spawner_system", &[])
+                .with(TimedMobSpawnerSystem, "timed_mob_spawner_system", &[])
+                .with(
+                    HealthThresholdSpawnerSystem,
+                    "health_threshold_spawner_system",
+                    &[],
+                )
                 .with(systems::AttractorSystem, "attractor_system", &[])
                 .with(
                     ItemMotion2DSystem::default(),
@@ -86,17 +126,18 @@ impl Default for MainGameState {
                 .with(
                     BlastMotion2DSystem::default(),
                     "blast_motion_2d_system",
-                    &["attractor_system"],
+                    &["attractor_system", "target_system"],
                 )
                 .with(
                     systems::BarrelRollAbilitySystem::default(),
                     "barrel_roll_ability_system",
                     &[],
                 )
+                .with(systems::SpecialAbilitySystem, "special_ability_system", &[])
                 .with(
                     MobMotion2DSystem,
                     "mob_motion_2d_system",
-                    &["mob_target_system"],
+                    &["target_system"],
                 )
                 .with(DespawnAtBorderSystem, "despawn_at_border_system", &[])
                 .with(PlayerMotion2DSystem, "spaceship_movement_system", &[])
@@ -157,20 +198,43 @@ impl Default for MainGameState {
                     "mob_blast_collision",
                     &["collision_handler_system"],
                 )
+                .with(
+                    BlastArenaBorderCollisionSystem::default(),
+                    "blast_arena_border_collision",
+                    &["collision_handler_system"],
+                )
                 .with(
                     systems::DefenseSystem::default(),
                     "defense_system",
                     &["spaceship_item_collision_system"],
                 )
+                .with(
+                    systems::EscortSystem::default(),
+                    "escort_system",
+                    &["mob_behavior_system", "despawn_at_border_system"],
+                )
                 .with(systems::SpaceshipSystem::default(), "spaceship_system", &[])
-                .with(systems::StoreSystem, "store_system", &[])
+                .with(systems::BombSystem::default(), "bomb_system", &[])
+                .with(systems::StoreSystem::default(), "store_system", &[])
                 .with(
                     StatTrackerSystem,
                     "stat_tracker_system",
                     &["store_system", "spaceship_system"],
                 )
+                .with(BuffIconSystem, "buff_icon_system", &["modifiers_system"])
+                .with(
+                    AnnouncementSystem::default(),
+                    "announcement_system",
+                    &["modifiers_system"],
+                )
                 .with(AutoFireSystem, "autoblaster_system", &[])
                 .with(ManualBlasterSystem, "manualblaster_system", &[])
+                .with(BeamSystem, "beam_system", &["spaceship_system"])
+                .with(
+                    SecondaryWeaponSystem::default(),
+                    "secondary_weapon_system",
+                    &[],
+                )
                 .with(
                     MobDestroyedSystem::default(),
                     "mob_destroyed_system",
@@ -182,6 +246,11 @@ impl Default for MainGameState {
                     &[],
                 )
                 .with(FadeSystem, "fade_system", &[])
+                .with(
+                    systems::MetaProgressionSystem::default(),
+                    "meta_progression_system",
+                    &[],
+                )
                 .build(),
         }
     }
@@ -194,6 +263,12 @@ impl SimpleState for MainGameState {
 
         self.dispatcher.setup(world);
 
+        // only unlocked items can be stocked by the store
+        world.read_resource::<UnlocksResource>().filter_stock(
+            &mut world.write_resource::<StoreResource>(),
+            &world.read_resource::<SaveProfileResource>(),
+        );
+
         initialize_audio(world);
         initialise_ui(world);
         initialize_status_bars(world);
@@ -219,9 +294,13 @@ impl SimpleState for MainGameState {
             0.005,
         );
         initialize_background(world, spritesheets.spritesheets["backgrounds"].clone());
-        initialize_spaceship(world, spritesheets.spritesheets["characters"].clone());
+        for (player_index, character) in self.characters.iter().enumerate() {
+            initialize_spaceship(world, character, player_index, self.characters.len());
+        }
         initialize_arena_barriers(world);
         initialize_store_icons(world, spritesheets.spritesheets["items"].clone());
+        initialize_buff_icons(world, spritesheets.spritesheets["items"].clone());
+        initialize_announcement(world);
         initialise_camera(world);
 
         world.insert(DebugLines::new());
@@ -235,15 +314,25 @@ impl SimpleState for MainGameState {
         world.insert(debug_lines_params);
     }
 
+    fn on_stop(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        data.world.read_resource::<SaveProfileResource>().save();
+    }
+
     fn on_pause(&mut self, data: StateData<'_, GameData<'_, '_>>) {
         self.is_paused = true;
 
+        // the shop shows its own text
+        if self.is_shopping {
+            return;
+        }
+
         let paused_text_entity = get_paused_text(data.world);
         self.pause_display = Some(paused_text_entity);
     }
 
     fn on_resume(&mut self, _data: StateData<'_, GameData<'_, '_>>) {
         self.is_paused = false;
+        self.is_shopping = false;
     }
 
     fn update(&mut self, data: &mut StateData<'_, GameData<'_, '_>>) -> SimpleTrans {
@@ -258,6 +347,22 @@ impl SimpleState for MainGameState {
             self.pause_display = None;
         }
 
+        // the run ends once every player has run out of lives
+        let players_remaining = data.world.read_storage::<PlayerComponent>().join().count();
+        if players_remaining == 0 {
+            return Trans::Switch(Box::new(GameOverState::default()));
+        }
+
+        // pause the game and open the shop between phases
+        let shop_requested = std::mem::replace(
+            &mut data.world.write_resource::<StoreResource>().shop_requested,
+            false,
+        );
+        if shop_requested {
+            self.is_shopping = true;
+            return Trans::Push(Box::new(ShopState::default()));
+        }
+
         Trans::None
     }
 
@@ -437,6 +542,43 @@ fn initialise_ui(world: &mut World) {
         .with(local_transform)
         .build();
 
+    // the bomb icon uses the bomb consumable's sprite
+    let bomb_sprite_render = {
+        let consumables_resource = world.read_resource::<ConsumablesResource>();
+        let bomb_sprite_data =
+            &consumables_resource.consumable_entities[&ConsumableType::Bomb].sprite_render_data;
+        SpriteRender {
+            sprite_sheet: world.read_resource::<SpriteSheetsResource>().spritesheets
+                [&bomb_sprite_data.spritesheet]
+                .clone(),
+            sprite_number: bomb_sprite_data.initial_index,
+        }
+    };
+
+    let mut local_transform = Transform::default();
+    local_transform.set_translation_xyz(ARENA_MAX_X + 10.0, ARENA_MIN_Y + 140.0, 0.9);
+
+    world
+        .create_entity()
+        .with(bomb_sprite_render)
+        .with(local_transform)
+        .build();
+
+    let lives_sprite_render = SpriteRender {
+        sprite_sheet: world.read_resource::<SpriteSheetsResource>().spritesheets["characters"]
+            .clone(),
+        sprite_number: LIVES_SPRITE_INDEX,
+    };
+
+    let mut local_transform = Transform::default();
+    local_transform.set_translation_xyz(ARENA_MAX_X + 10.0, ARENA_MIN_Y + 102.0, 0.9);
+
+    world
+        .create_entity()
+        .with(lives_sprite_render)
+        .with(local_transform)
+        .build();
+
     let font = world.read_resource::<Loader>().load(
         "font/SpaceMadness.ttf",
         TtfFormat,
@@ -490,68 +632,71 @@ fn initialise_ui(world: &mut World) {
         ))
         .build();
 
-    let item_price_1_transform = UiTransform::new(
-        "item_price_0".to_string(),
-        Anchor::BottomRight,
-        Anchor::BottomRight,
+    let bombs_count_transform = UiTransform::new(
+        "bombs_count".to_string(),
+        Anchor::MiddleRight,
+        Anchor::MiddleRight,
         -6.0,
-        130.0,
+        29.0,
         0.9,
         50.0,
         45.0,
     );
-    let item_price_1 = world
+
+    let bombs_count = world
         .create_entity()
-        .with(item_price_1_transform)
+        .with(bombs_count_transform)
         .with(UiText::new(
             font.clone(),
-            "$0".to_string(),
+            "x0".to_string(),
             [1.0, 1.0, 1.0, 1.0],
-            15.0,
+            20.0,
             LineMode::Single,
             Anchor::Middle,
         ))
         .build();
 
-    let item_price_2_transform = UiTransform::new(
-        "item_price_1".to_string(),
-        Anchor::BottomRight,
-        Anchor::BottomRight,
+    let lives_count_transform = UiTransform::new(
+        "lives_count".to_string(),
+        Anchor::MiddleRight,
+        Anchor::MiddleRight,
         -6.0,
-        90.0,
+        -9.0,
         0.9,
         50.0,
         45.0,
     );
-    let item_price_2 = world
+
+    let lives_count = world
         .create_entity()
-        .with(item_price_2_transform)
+        .with(lives_count_transform)
         .with(UiText::new(
             font.clone(),
-            "$0".to_string(),
+            "x0".to_string(),
             [1.0, 1.0, 1.0, 1.0],
-            15.0,
+            20.0,
             LineMode::Single,
             Anchor::Middle,
         ))
         .build();
 
-    let item_price_3_transform = UiTransform::new(
-        "item_price_2".to_string(),
-        Anchor::BottomRight,
-        Anchor::BottomRight,
+    let secondary_weapon_transform = UiTransform::new(
+        "secondary_weapon".to_string(),
+        Anchor::MiddleRight,
+        Anchor::MiddleRight,
         -6.0,
-        50.0,
+        10.0,
         0.9,
         50.0,
         45.0,
     );
-    let item_price_3 = world
+
+    let secondary_weapon = world
         .create_entity()
-        .with(item_price_3_transform)
+        .with(secondary_weapon_transform)
         .with(UiText::new(
-            font,
-            "$0".to_string(),
+            font.clone(),
+            "".to_string(),
             [1.0, 1.0, 1.0, 1.0],
             15.0,
             LineMode::Single,
@@ -559,12 +704,69 @@ fn initialise_ui(world: &mut World) {
         ))
         .build();
 
+    // prices are positioned next to their slots by the stat tracker system
+    let max_slot_count = {
+        let store_resource = world.read_resource::<StoreResource>();
+        store_resource.max_slot_count.max(store_resource.slot_count)
+    };
+    let item_prices = (0..max_slot_count)
+        .map(|inventory_index| {
+            let item_price_transform = UiTransform::new(
+                format!("item_price_{}", inventory_index),
+                Anchor::BottomRight,
+                Anchor::BottomRight,
+                -6.0,
+                ITEM_PRICES_TOP_Y,
+                0.9,
+                50.0,
+                45.0,
+            );
+            world
+                .create_entity()
+                .with(item_price_transform)
+                .with(UiText::new(
+                    font.clone(),
+                    "".to_string(),
+                    [1.0, 1.0, 1.0, 1.0],
+                    15.0,
+                    LineMode::Single,
+                    Anchor::Middle,
+                ))
+                .build()
+        })
+        .collect();
+
+    let store_details_transform = UiTransform::new(
+        "store_details".to_string(),
+        Anchor::BottomMiddle,
+        Anchor::BottomMiddle,
+        0.0,
+        10.0,
+        0.9,
+        600.0,
+        60.0,
+    );
+    let store_details = world
+        .create_entity()
+        .with(store_details_transform)
+        .with(UiText::new(
+            font,
+            "".to_string(),
+            [1.0, 1.0, 1.0, 1.0],
+            12.0,
+            LineMode::Wrap,
+            Anchor::Middle,
+        ))
+        .build();
+
     world.insert(TrackedStats {
         currency: currency_count,
         shields: shields_count,
-        item_price_1,
-        item_price_2,
-        item_price_3,
+        bombs: bombs_count,
+        lives: lives_count,
+        secondary_weapon,
+        item_prices,
+        store_details,
     });
 }
 
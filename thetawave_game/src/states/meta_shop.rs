@@ -0,0 +1,144 @@
+use amethyst::{
+    assets::Loader,
+    ecs::prelude::Entity,
+    input::{is_key_down, VirtualKeyCode},
+    prelude::*,
+    ui::{Anchor, LineMode, TtfFormat, UiText, UiTransform},
+};
+use thetawave_lib::{
+    resources::{SaveProfileResource, Unlock, UnlocksResource},
+    spawnable::resources::ItemsResource,
+};
+
+/// Lets players spend currency saved in the profile on characters and store items
+#[derive(Default)]
+pub struct MetaShopState {
+    /// Index of the highlighted unlock
+    selected: usize,
+    /// Text displaying the unlocks and their prices
+    display: Option<Entity>,
+}
+
+impl SimpleState for MetaShopState {
+    fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        let world = data.world;
+
+        let font_handle = world.read_resource::<Loader>().load(
+            "font/SpaceMadness.ttf",
+            TtfFormat,
+            (),
+            &world.read_resource(),
+        );
+        let ui_transform = UiTransform::new(
+            String::from("meta_shop_text"),
+            Anchor::Middle,
+            Anchor::Middle,
+            0.0,
+            0.0,
+            0.0,
+            400.0,
+            300.0,
+        );
+        let ui_text = UiText::new(
+            font_handle,
+            String::new(),
+            [1.0, 1.0, 1.0, 1.0],
+            18.0,
+            LineMode::Wrap,
+            Anchor::Middle,
+        );
+
+        self.display = Some(
+            world
+                .create_entity()
+                .with(ui_transform)
+                .with(ui_text)
+                .build(),
+        );
+        self.update_display(world);
+    }
+
+    fn on_stop(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        if let Some(display) = self.display.take() {
+            data.world
+                .delete_entity(display)
+                .expect("Failed to remove meta shop text.");
+        }
+    }
+
+    fn handle_event(
+        &mut self,
+        data: StateData<'_, GameData<'_, '_>>,
+        event: StateEvent,
+    ) -> SimpleTrans {
+        if let StateEvent::Window(event) = &event {
+            if is_key_down(&event, VirtualKeyCode::Escape)
+                || is_key_down(&event, VirtualKeyCode::Tab)
+            {
+                return Trans::Pop;
+            }
+
+            let unlock_count = data.world.read_resource::<UnlocksResource>().unlocks.len();
+            if unlock_count == 0 {
+                return Trans::None;
+            }
+
+            if is_key_down(&event, VirtualKeyCode::Up) || is_key_down(&event, VirtualKeyCode::W) {
+                self.selected = (self.selected + unlock_count - 1) % unlock_count;
+                self.update_display(data.world);
+            } else if is_key_down(&event, VirtualKeyCode::Down)
+                || is_key_down(&event, VirtualKeyCode::S)
+            {
+                self.selected = (self.selected + 1) % unlock_count;
+                self.update_display(data.world);
+            } else if is_key_down(&event, VirtualKeyCode::Return)
+                || is_key_down(&event, VirtualKeyCode::Space)
+            {
+                let (unlock, price) =
+                    data.world.read_resource::<UnlocksResource>().unlocks[self.selected].clone();
+                {
+                    // unlocks are saved as soon as they are bought
+                    let mut save_profile = data.world.write_resource::<SaveProfileResource>();
+                    if save_profile.buy(&unlock, price) {
+                        save_profile.save();
+                    }
+                }
+                self.update_display(data.world);
+            }
+        }
+        Trans::None
+    }
+}
+
+impl MetaShopState {
+    /// Show the profile's currency and the unlocks with the highlighted one marked
+    fn update_display(&self, world: &mut World) {
+        if let Some(display) = self.display {
+            let unlocks = world.read_resource::<UnlocksResource>();
+            let save_profile = world.read_resource::<SaveProfileResource>();
+            let items_resource = world.read_resource::<ItemsResource>();
+
+            let mut text = format!(
+                "currency: ${}  best phase: {}\n",
+                save_profile.currency, save_profile.best_phase
+            );
+            for (i, (unlock, price)) in unlocks.unlocks.iter().enumerate() {
+                let name = match unlock {
+                    Unlock::Character(character) => character.clone(),
+                    Unlock::Item(item_type) => items_resource.item_entities[item_type].name.clone(),
+                };
+                let status = if save_profile.is_unlocked(unlock) {
+                    String::from("owned")
+                } else {
+                    format!("${}", price)
+                };
+                let marker = if i == self.selected { ">" } else { " " };
+                text.push_str(&format!("\n{} {} {}", marker, name, status));
+            }
+
+            if let Some(ui_text) = world.write_storage::<UiText>().get_mut(display) {
+                ui_text.text = text;
+            }
+        }
+    }
+}
@@ -1,4 +1,11 @@
+mod character_select;
+mod game_over;
 mod main_game;
+mod meta_shop;
 mod paused;
+mod shop;
 
-pub use self::{main_game::MainGameState, paused::PausedState};
+pub use self::{
+    character_select::CharacterSelectState, game_over::GameOverState, main_game::MainGameState,
+    meta_shop::MetaShopState, paused::PausedState, shop::ShopState,
+};
@@ -0,0 +1,104 @@
+use amethyst::{
+    assets::Loader,
+    ecs::prelude::{Dispatcher, DispatcherBuilder, Entity},
+    input::InputEvent,
+    prelude::*,
+    ui::{Anchor, LineMode, TtfFormat, UiText, UiTransform},
+};
+use thetawave_lib::{resources::StoreResource, systems, visual::systems::StatTrackerSystem};
+
+/// Shop opened between phases, the rest of the game is paused while the shop is open
+pub struct ShopState {
+    shop_display: Option<Entity>,
+    dispatcher: Dispatcher<'static, 'static>,
+}
+
+impl Default for ShopState {
+    fn default() -> Self {
+        ShopState {
+            shop_display: None,
+            dispatcher: DispatcherBuilder::new()
+                .with(systems::StoreSystem::default(), "store_system", &[])
+                .with(StatTrackerSystem, "stat_tracker_system", &["store_system"])
+                .build(),
+        }
+    }
+}
+
+impl SimpleState for ShopState {
+    fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        let world = data.world;
+
+        self.dispatcher.setup(world);
+
+        world.write_resource::<StoreResource>().shop_open = true;
+        self.shop_display = Some(get_shop_text(world));
+    }
+
+    fn on_stop(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        {
+            // the main game's store system redraws the icons shown by the shop's store system
+            let mut store_resource = data.world.write_resource::<StoreResource>();
+            store_resource.shop_open = false;
+            store_resource.icons_outdated = true;
+        }
+
+        if let Some(shop_text_entity) = self.shop_display.take() {
+            data.world
+                .delete_entity(shop_text_entity)
+                .expect("Failed to remove shop text.");
+        }
+    }
+
+    fn update(&mut self, data: &mut StateData<'_, GameData<'_, '_>>) -> SimpleTrans {
+        self.dispatcher.dispatch(data.world);
+
+        Trans::None
+    }
+
+    fn handle_event(
+        &mut self,
+        _data: StateData<'_, GameData<'_, '_>>,
+        event: StateEvent,
+    ) -> SimpleTrans {
+        if let StateEvent::Input(InputEvent::ActionPressed(action)) = &event {
+            if action == "exit_shop" {
+                return Trans::Pop;
+            }
+        }
+        Trans::None
+    }
+}
+
+fn get_shop_text(world: &mut World) -> Entity {
+    let font_handle = world.read_resource::<Loader>().load(
+        "font/SpaceMadness.ttf",
+        TtfFormat,
+        (),
+        &world.read_resource(),
+    );
+    let ui_transform = UiTransform::new(
+        String::from("shop_text"),
+        Anchor::Middle,
+        Anchor::Middle,
+        0.0,
+        0.0,
+        0.0,
+        400.0,
+        30.0,
+    );
+    let ui_text = UiText::new(
+        font_handle,
+        String::from("shop open, press enter to continue"),
+        [1.0, 1.0, 1.0, 1.0],
+        20.0,
+        LineMode::Single,
+        Anchor::Middle,
+    );
+
+    world
+        .create_entity()
+        .with(ui_transform)
+        .with(ui_text)
+        .build()
+}
@@ -1,7 +1,7 @@
 use crate::resources::SoundsConfig;
 use amethyst::{
     assets::{AssetStorage, Handle, Loader},
-    audio::{output::Output, OggFormat, Source, SourceHandle},
+    audio::{output::Output, OggFormat, Source, SourceHandle, WavFormat},
     ecs::{World, WorldExt},
 };
 
@@ -11,8 +11,13 @@ pub struct Sounds {
     pub sound_effects: HashMap<String, SourceHandle>,
 }
 
+/// Load a sound from an ogg file, or from a wav file if the file name ends with .wav
 fn load_audio_track(loader: &Loader, world: &World, file: &str) -> SourceHandle {
-    loader.load(file, OggFormat, (), &world.read_resource())
+    if file.ends_with(".wav") {
+        loader.load(file, WavFormat, (), &world.read_resource())
+    } else {
+        loader.load(file, OggFormat, (), &world.read_resource())
+    }
 }
 
 pub fn initialize_audio(world: &mut World) {
@@ -1,3 +1,4 @@
+use crate::components::player_action;
 use amethyst::{
     ecs::prelude::{Component, DenseVecStorage, Read},
     input::{InputHandler, StringBindings},
@@ -24,7 +25,7 @@ pub trait CooldownAbility {
         }
     }
 
-    fn execute_action(&mut self, input: &Read<InputHandler<StringBindings>>);
+    fn execute_action(&mut self, input: &Read<InputHandler<StringBindings>>, player_index: usize);
     fn end_action(&mut self);
 }
 
@@ -37,6 +38,12 @@ pub struct BarrelRollAbilityComponent {
     pub action_direction: AbilityDirection,
     pub speed: f32,
     pub steel_barrel: bool,
+    /// Enemy blasts pass through the player while rolling
+    #[serde(default)]
+    pub blast_immune: bool,
+    /// Enemy blasts are reflected back as ally blasts while rolling
+    #[serde(default)]
+    pub reflect_blasts: bool,
 }
 
 impl Component for BarrelRollAbilityComponent {
@@ -71,9 +78,13 @@ impl CooldownAbility for BarrelRollAbilityComponent {
         self.action_direction = AbilityDirection::None;
     }
 
-    fn execute_action(&mut self, input: &Read<InputHandler<StringBindings>>) {
-        let barrel_left = input.action_is_down("barrel_left").unwrap();
-        let barrel_right = input.action_is_down("barrel_right").unwrap();
+    fn execute_action(&mut self, input: &Read<InputHandler<StringBindings>>, player_index: usize) {
+        let barrel_left = input
+            .action_is_down(&player_action(player_index, "barrel_left"))
+            .unwrap();
+        let barrel_right = input
+            .action_is_down(&player_action(player_index, "barrel_right"))
+            .unwrap();
 
         if self.execute_timer <= 0.0 {
             if barrel_left {
@@ -102,3 +113,80 @@ impl BarrelRollAbilityComponent {
         }
     }
 }
+
+/// Effects of special abilities chosen per character
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub enum SpecialAbilityType {
+    /// Push enemy blasts within the radius away from the player
+    ShieldBurst { radius: f32, repel_speed: f32 },
+    /// Instantly move the player in the direction they are steering
+    Teleport { distance: f32 },
+    /// Slow down everything other than players within the radius
+    TimeSlow { radius: f32, time_scale: f32 },
+    /// Leave behind a decoy that enemies target instead of players
+    Decoy,
+}
+
+/// Special ability activated with the ability binding
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct SpecialAbilityComponent {
+    /// Effect of the ability
+    pub ability_type: SpecialAbilityType,
+    /// Time to recharge the ability after use
+    pub execute_cooldown: f32,
+    /// Stores countdown time from execute cooldown value
+    #[serde(default)]
+    pub execute_timer: f32,
+    /// Time that the ability stays active after use
+    pub action_duration: f32,
+    /// Stores countdown time from action duration value
+    #[serde(default)]
+    pub action_timer: f32,
+}
+
+impl Component for SpecialAbilityComponent {
+    type Storage = DenseVecStorage<Self>;
+}
+
+impl CooldownAbility for SpecialAbilityComponent {
+    fn is_active(&self) -> bool {
+        self.action_timer > 0.0
+    }
+
+    fn update_execution_timer(&mut self, dt: f32) {
+        if self.execute_timer > 0.0 {
+            self.execute_timer -= dt;
+        }
+    }
+
+    fn update_action(&mut self, dt: f32) {
+        self.action_timer -= dt;
+
+        if self.action_timer <= 0.0 {
+            self.end_action();
+        }
+    }
+
+    fn end_action(&mut self) {
+        self.action_timer = 0.0;
+    }
+
+    fn execute_action(&mut self, input: &Read<InputHandler<StringBindings>>, player_index: usize) {
+        let ability = input
+            .action_is_down(&player_action(player_index, "ability"))
+            .unwrap();
+
+        if ability && self.execute_timer <= 0.0 {
+            self.action_timer = self.action_duration;
+            self.execute_timer = self.execute_cooldown;
+        }
+    }
+}
+
+/// Decoy targeted by enemies in place of players
+#[derive(Clone, Debug, Default)]
+pub struct DecoyComponent;
+
+impl Component for DecoyComponent {
+    type Storage = DenseVecStorage<Self>;
+}
@@ -0,0 +1,40 @@
+use crate::{entities::MobType, weapons::BlastType};
+use amethyst::ecs::prelude::{Component, DenseVecStorage};
+use serde::{Deserialize, Serialize};
+
+/// Sides of combat between mobs, blasts and players
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+pub enum Faction {
+    Ally,
+    Enemy,
+    Neutral,
+}
+
+impl Faction {
+    /// Faction of a mob of the given type
+    pub fn from_mob_type(mob_type: &MobType) -> Self {
+        match mob_type {
+            MobType::Ally(_) => Faction::Ally,
+            MobType::Enemy(_) => Faction::Enemy,
+            MobType::Neutral(_) => Faction::Neutral,
+        }
+    }
+
+    /// Faction of a blast of the given type
+    pub fn from_blast_type(blast_type: &BlastType) -> Self {
+        match blast_type {
+            BlastType::Ally | BlastType::AllyCritical | BlastType::AllyPoison => Faction::Ally,
+            BlastType::Enemy => Faction::Enemy,
+        }
+    }
+}
+
+/// Used for determining which side an entity fights for
+#[derive(Clone, Debug)]
+pub struct FactionComponent {
+    pub faction: Faction,
+}
+
+impl Component for FactionComponent {
+    type Storage = DenseVecStorage<Self>;
+}
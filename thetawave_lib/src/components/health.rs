@@ -6,6 +6,13 @@ pub struct HealthComponent {
     pub max_value: f32,
     pub value: f32,
     pub armor: usize,
+    #[serde(default)]
+    pub shields: f32,
+    #[serde(default)]
+    pub invulnerability_timer: f32,
+    /// Time that the entity is invulnerable for after taking damage
+    #[serde(default)]
+    pub invulnerability_duration: f32,
 }
 
 impl Component for HealthComponent {
@@ -21,11 +28,44 @@ impl HealthComponent {
         }
     }
 
-    pub fn take_damage(&mut self, damage_value: f32) {
-        if self.armor == 0 {
-            self.value -= damage_value;
+    pub fn make_invulnerable(&mut self, duration: f32) {
+        self.invulnerability_timer = self.invulnerability_timer.max(duration);
+    }
+
+    pub fn update_invulnerability(&mut self, delta_time: f32) {
+        self.invulnerability_timer = (self.invulnerability_timer - delta_time).max(0.0);
+    }
+
+    /// Apply damage through armor and shields, returns the health lost
+    pub fn take_damage(&mut self, damage_value: f32) -> f32 {
+        if self.invulnerability_timer > 0.0 {
+            return 0.0;
+        }
+
+        let health_damage = if self.armor == 0 {
+            let absorbed_damage = damage_value.min(self.shields);
+            self.shields -= absorbed_damage;
+            damage_value - absorbed_damage
         } else {
             self.armor -= 1;
+            0.0
+        };
+        self.value -= health_damage;
+
+        self.make_invulnerable(self.invulnerability_duration);
+        health_damage
+    }
+
+    /// Apply damage dealt every frame, armor blocks the damage without being used up
+    /// and no invulnerability is granted, returns the health lost
+    pub fn take_continuous_damage(&mut self, damage_value: f32) -> f32 {
+        if self.invulnerability_timer > 0.0 || self.armor > 0 {
+            return 0.0;
         }
+
+        let absorbed_damage = damage_value.min(self.shields);
+        self.shields -= absorbed_damage;
+        self.value -= damage_value - absorbed_damage;
+        damage_value - absorbed_damage
     }
 }
@@ -2,16 +2,23 @@ mod abilities;
 mod attraction;
 mod barriers;
 mod boss;
+mod faction;
 mod health;
 mod planet;
 mod player;
+mod stat_sheet;
 
 pub use self::{
-    abilities::{AbilityDirection, BarrelRollAbilityComponent, CooldownAbility},
+    abilities::{
+        AbilityDirection, BarrelRollAbilityComponent, CooldownAbility, DecoyComponent,
+        SpecialAbilityComponent, SpecialAbilityType,
+    },
     attraction::{AttractData, AttractorCategory, AttractorComponent},
     barriers::{BarrierComponent, PushDirection},
     boss::RepeaterComponent,
+    faction::{Faction, FactionComponent},
     health::HealthComponent,
     planet::PlanetComponent,
-    player::PlayerComponent,
+    player::{player_action, PlayerComponent},
+    stat_sheet::{ActiveBuff, StatSheetComponent},
 };
@@ -7,8 +7,51 @@ pub struct PlayerComponent {
     pub money: usize,
     pub collision_damage: f32,
     pub items: Vec<ItemType>,
+    #[serde(default)]
+    pub bombs: usize,
+    /// Number of times the player can respawn after being destroyed
+    #[serde(default)]
+    pub lives: usize,
+    /// Luck before modifiers, luck improves drops
+    #[serde(default)]
+    pub luck: f32,
+    /// Index of the player used for choosing input bindings
+    #[serde(default)]
+    pub player_index: usize,
+    /// Index of the held item sold by the sell action
+    #[serde(default)]
+    pub sell_index: usize,
 }
 
 impl Component for PlayerComponent {
     type Storage = DenseVecStorage<Self>;
 }
+
+impl PlayerComponent {
+    /// Name of the given input binding for this player
+    pub fn action(&self, action: &str) -> String {
+        player_action(self.player_index, action)
+    }
+
+    /// Held item selected for selling, the last item is selected if the selection is past the end
+    pub fn item_to_sell(&self) -> Option<&ItemType> {
+        self.items
+            .get(self.sell_index)
+            .or_else(|| self.items.last())
+    }
+
+    /// Select the next held item for selling
+    pub fn select_next_item(&mut self) {
+        self.sell_index = (self.sell_index + 1) % self.items.len().max(1);
+    }
+}
+
+/// Name of the given input binding for the player with the given index
+/// (the first player uses unprefixed binding names)
+pub fn player_action(player_index: usize, action: &str) -> String {
+    if player_index == 0 {
+        action.to_string()
+    } else {
+        format!("p{}_{}", player_index + 1, action)
+    }
+}
@@ -0,0 +1,544 @@
+use crate::{
+    components::{
+        AttractorCategory, AttractorComponent, BarrelRollAbilityComponent, HealthComponent,
+        PlayerComponent,
+    },
+    entities::{ItemType, SpawnableType},
+    motion::components::Motion2DComponent,
+    spawnable::resources::{
+        BuffData, BuffStacking, BuffType, BuffsResource, Modifier, StatChange, StatClampsResource,
+        StatType, SynergiesResource, SynergyType,
+    },
+    weapons::components::{BlasterComponent, ManualFireComponent},
+};
+use amethyst::ecs::prelude::{Component, DenseVecStorage};
+
+/// Base stats of a player and the modifiers applied on top of them
+#[derive(Clone, Debug)]
+pub struct StatSheetComponent {
+    /// Blaster stats before modifiers
+    pub base_blaster: BlasterComponent,
+    /// Fire rate and charge stats before modifiers
+    pub base_manual_fire: ManualFireComponent,
+    /// Movement stats before modifiers
+    pub base_motion2d: Motion2DComponent,
+    /// Maximum health before modifiers
+    pub base_max_health: f32,
+    /// Barrel roll stats and flags before modifiers
+    pub base_barrel_roll_ability: BarrelRollAbilityComponent,
+    /// Attractor stats and flags before modifiers
+    pub base_attractor: AttractorComponent,
+    /// Luck before modifiers
+    pub base_luck: f32,
+    /// Modifiers of collected spawnables in the order they were collected
+    pub active_modifiers: Vec<(SpawnableType, Vec<Modifier>)>,
+    /// Temporary modifiers that expire when their timers run out
+    pub buffs: Vec<ActiveBuff>,
+    /// Modifiers of unlocked item synergies
+    pub synergies: Vec<(SynergyType, Vec<Modifier>)>,
+}
+
+/// Buff applied to a player and its remaining time
+#[derive(Clone, Debug)]
+pub struct ActiveBuff {
+    /// Type of the buff
+    pub buff_type: BuffType,
+    /// Time in seconds until the buff expires
+    pub timer: f32,
+    /// Modifiers applied while the buff is active
+    pub modifiers: Vec<Modifier>,
+}
+
+impl Component for StatSheetComponent {
+    type Storage = DenseVecStorage<Self>;
+}
+
+impl StatSheetComponent {
+    /// Create a stat sheet using the current values of a player's components as base stats
+    pub fn new(
+        blaster: &BlasterComponent,
+        manual_fire: &ManualFireComponent,
+        motion2d: &Motion2DComponent,
+        health: &HealthComponent,
+        barrel_roll_ability: &BarrelRollAbilityComponent,
+        attractor: &AttractorComponent,
+        player: &PlayerComponent,
+    ) -> Self {
+        StatSheetComponent {
+            base_blaster: blaster.clone(),
+            base_manual_fire: manual_fire.clone(),
+            base_motion2d: motion2d.clone(),
+            base_max_health: health.max_value,
+            base_barrel_roll_ability: barrel_roll_ability.clone(),
+            base_attractor: attractor.clone(),
+            base_luck: player.luck,
+            active_modifiers: vec![],
+            buffs: vec![],
+            synergies: vec![],
+        }
+    }
+
+    /// Add the modifiers of a collected spawnable
+    pub fn add_modifiers(&mut self, spawnable_type: SpawnableType, modifiers: Vec<Modifier>) {
+        self.active_modifiers.push((spawnable_type, modifiers));
+    }
+
+    /// Remove the modifiers of the most recently collected copy of a spawnable
+    pub fn remove_modifiers(&mut self, spawnable_type: &SpawnableType) -> Option<Vec<Modifier>> {
+        self.active_modifiers
+            .iter()
+            .rposition(|(active_type, _)| active_type == spawnable_type)
+            .map(|index| self.active_modifiers.remove(index).1)
+    }
+
+    /// Apply a buff using its stacking rule if it is already active
+    pub fn add_buff(&mut self, buff_type: BuffType, buff_data: &BuffData) {
+        let active_buff = self
+            .buffs
+            .iter_mut()
+            .find(|active_buff| active_buff.buff_type == buff_type);
+
+        match (buff_data.stacking, active_buff) {
+            (BuffStacking::Refresh, Some(active_buff)) => {
+                active_buff.timer = buff_data.duration;
+            }
+            (BuffStacking::Extend, Some(active_buff)) => {
+                active_buff.timer += buff_data.duration;
+            }
+            _ => {
+                self.buffs.push(ActiveBuff {
+                    buff_type,
+                    timer: buff_data.duration,
+                    modifiers: buff_data.modifiers.clone(),
+                });
+            }
+        }
+    }
+
+    /// Count down buff timers and remove expired buffs, returns true if any buff expired
+    pub fn update_buffs(&mut self, delta_seconds: f32) -> bool {
+        let buff_count = self.buffs.len();
+
+        for active_buff in self.buffs.iter_mut() {
+            active_buff.timer -= delta_seconds;
+        }
+        self.buffs.retain(|active_buff| active_buff.timer > 0.0);
+
+        self.buffs.len() != buff_count
+    }
+
+    /// Unlock synergies of the held items and lock synergies missing an item,
+    /// returns the newly unlocked synergies
+    pub fn update_synergies(
+        &mut self,
+        items: &[ItemType],
+        synergies_resource: &SynergiesResource,
+    ) -> Vec<SynergyType> {
+        self.synergies.retain(|(synergy_type, _)| {
+            synergies_resource[synergy_type]
+                .items
+                .iter()
+                .all(|item_type| items.contains(item_type))
+        });
+
+        let mut unlocked_synergies = vec![];
+        for (synergy_type, synergy_data) in synergies_resource.iter() {
+            if !self
+                .synergies
+                .iter()
+                .any(|(active_type, _)| active_type == synergy_type)
+                && synergy_data
+                    .items
+                    .iter()
+                    .all(|item_type| items.contains(item_type))
+            {
+                self.synergies
+                    .push((*synergy_type, synergy_data.modifiers.clone()));
+                unlocked_synergies.push(*synergy_type);
+            }
+        }
+
+        unlocked_synergies
+    }
+
+    /// All active modifiers in the order they were collected, followed by synergy and buff modifiers
+    pub fn modifiers(&self) -> impl Iterator<Item = &Modifier> {
+        self.active_modifiers
+            .iter()
+            .flat_map(|(_, modifiers)| modifiers.iter())
+            .chain(
+                self.synergies
+                    .iter()
+                    .flat_map(|(_, modifiers)| modifiers.iter()),
+            )
+            .chain(
+                self.buffs
+                    .iter()
+                    .flat_map(|active_buff| active_buff.modifiers.iter()),
+            )
+    }
+
+    /// Returns true if any active modifier changes the stat
+    pub fn is_modified(&self, stat_type: StatType) -> bool {
+        self.modifiers()
+            .filter_map(|modifier| modifier.stat_change())
+            .any(|(modified_type, _)| modified_type == stat_type)
+    }
+
+    /// Effective value of a stat: the base value plus all additions, times all multipliers, clamped
+    pub fn stat_value(
+        &self,
+        stat_type: StatType,
+        base_value: f32,
+        stat_clamps: &StatClampsResource,
+    ) -> f32 {
+        let (added, multiplier) = self
+            .modifiers()
+            .filter_map(|modifier| modifier.stat_change())
+            .filter(|(modified_type, _)| *modified_type == stat_type)
+            .fold(
+                (0.0, 1.0),
+                |(added, multiplier), (_, change)| match change {
+                    StatChange::Add(val) => (added + val, multiplier),
+                    StatChange::Multiply(val) => (added, multiplier * val),
+                },
+            );
+
+        let value = (base_value + added) * multiplier;
+
+        stat_clamps
+            .get(&stat_type)
+            .map_or(value, |stat_clamp| stat_clamp.apply(value))
+    }
+
+    /// Base value of a stat before modifiers
+    pub fn base_value(&self, stat_type: StatType) -> f32 {
+        let attract_data =
+            |category: AttractorCategory| self.base_attractor.attracted_spawnables.get(&category);
+
+        match stat_type {
+            StatType::ProjectileFirePeriod => self.base_manual_fire.period,
+            StatType::ChargeTime => self.base_manual_fire.charge_time.unwrap_or(0.0),
+            StatType::ProjectileDamage => self.base_blaster.damage,
+            StatType::ProjectileCount => self.base_blaster.count as f32,
+            StatType::ProjectileSize => self.base_blaster.size_multiplier,
+            StatType::ProjectilePiercing => self.base_blaster.piercing as f32,
+            StatType::ProjectileBounces => self.base_blaster.bounces as f32,
+            StatType::ProjectileExplosionRadius => self.base_blaster.explosion_radius,
+            StatType::ProjectileLifetime => self.base_blaster.lifetime.unwrap_or(0.0),
+            StatType::ProjectileSpread => self.base_blaster.spread_angle,
+            StatType::CriticalDamageChance => self.base_blaster.crit_chance,
+            StatType::PoisonChance => self.base_blaster.poison_chance,
+            StatType::MaximumSpeed => self.base_motion2d.max_speed.x,
+            StatType::Acceleration => self.base_motion2d.acceleration.x,
+            StatType::Deceleration => self.base_motion2d.deceleration.x,
+            StatType::AbilityCooldown => self.base_barrel_roll_ability.execute_cooldown,
+            StatType::MaximumHealth => self.base_max_health,
+            StatType::ConsumableAttractorRadius => attract_data(AttractorCategory::Consumable)
+                .map_or(0.0, |attract_data| attract_data.radius),
+            StatType::ConsumableAttractorAcceleration => {
+                attract_data(AttractorCategory::Consumable)
+                    .map_or(0.0, |attract_data| attract_data.acceleration)
+            }
+            StatType::ItemAttractorRadius => attract_data(AttractorCategory::Item)
+                .map_or(0.0, |attract_data| attract_data.radius),
+            StatType::ItemAttractorAcceleration => attract_data(AttractorCategory::Item)
+                .map_or(0.0, |attract_data| attract_data.acceleration),
+            StatType::BlastAttractorRadius => attract_data(AttractorCategory::Blast)
+                .map_or(0.0, |attract_data| attract_data.radius),
+            StatType::BlastAttractorAcceleration => attract_data(AttractorCategory::Blast)
+                .map_or(0.0, |attract_data| attract_data.acceleration),
+            StatType::Luck => self.base_luck,
+        }
+    }
+
+    /// Luck of the player, used when rolling drops
+    pub fn luck(&self, stat_clamps: &StatClampsResource) -> f32 {
+        self.stat_value(StatType::Luck, self.base_luck, stat_clamps)
+    }
+
+    /// Current and resulting values of each stat changed by collecting a spawnable's modifiers,
+    /// buffs are previewed with the modifiers they apply while active
+    pub fn preview(
+        &self,
+        spawnable_type: &SpawnableType,
+        modifiers: &[Modifier],
+        stat_clamps: &StatClampsResource,
+        buffs_resource: &BuffsResource,
+    ) -> Vec<(StatType, f32, f32)> {
+        let modifiers: Vec<Modifier> = modifiers
+            .iter()
+            .flat_map(|modifier| match modifier {
+                Modifier::Buff(buff_type) => buffs_resource[buff_type].modifiers.clone(),
+                _ => vec![modifier.clone()],
+            })
+            .collect();
+
+        let mut previewed_sheet = self.clone();
+        previewed_sheet.add_modifiers(spawnable_type.clone(), modifiers.clone());
+
+        let mut stat_types: Vec<StatType> = vec![];
+        for (stat_type, _) in modifiers
+            .iter()
+            .filter_map(|modifier| modifier.stat_change())
+        {
+            if !stat_types.contains(&stat_type) {
+                stat_types.push(stat_type);
+            }
+        }
+
+        stat_types
+            .into_iter()
+            .map(|stat_type| {
+                let base_value = self.base_value(stat_type);
+                (
+                    stat_type,
+                    self.stat_value(stat_type, base_value, stat_clamps),
+                    previewed_sheet.stat_value(stat_type, base_value, stat_clamps),
+                )
+            })
+            .collect()
+    }
+
+    /// Recompute the stats of a player's components from the base stats and active modifiers
+    pub fn apply(
+        &self,
+        stat_clamps: &StatClampsResource,
+        blaster: &mut BlasterComponent,
+        manual_fire: &mut ManualFireComponent,
+        motion2d: &mut Motion2DComponent,
+        health: &mut HealthComponent,
+        barrel_roll_ability: &mut BarrelRollAbilityComponent,
+        attractor: &mut AttractorComponent,
+    ) {
+        let stat = |stat_type: StatType, base_value: f32| {
+            self.stat_value(stat_type, base_value, stat_clamps)
+        };
+        let count_stat = |stat_type: StatType, base_value: usize| {
+            stat(stat_type, base_value as f32).round().max(0.0) as usize
+        };
+        // optional stats are enabled by a base value or the first modifier
+        let optional_stat = |stat_type: StatType, base_value: Option<f32>| {
+            if base_value.is_some() || self.is_modified(stat_type) {
+                Some(stat(stat_type, base_value.unwrap_or(0.0))).filter(|val| *val > 0.0)
+            } else {
+                None
+            }
+        };
+
+        blaster.count = count_stat(StatType::ProjectileCount, self.base_blaster.count);
+        blaster.damage = stat(StatType::ProjectileDamage, self.base_blaster.damage);
+        blaster.size_multiplier = stat(StatType::ProjectileSize, self.base_blaster.size_multiplier);
+        blaster.piercing = count_stat(StatType::ProjectilePiercing, self.base_blaster.piercing);
+        blaster.bounces = count_stat(StatType::ProjectileBounces, self.base_blaster.bounces);
+        blaster.explosion_radius = stat(
+            StatType::ProjectileExplosionRadius,
+            self.base_blaster.explosion_radius,
+        );
+        blaster.lifetime = optional_stat(StatType::ProjectileLifetime, self.base_blaster.lifetime);
+        blaster.spread_angle = stat(StatType::ProjectileSpread, self.base_blaster.spread_angle);
+        blaster.crit_chance = stat(
+            StatType::CriticalDamageChance,
+            self.base_blaster.crit_chance,
+        );
+        blaster.poison_chance = stat(StatType::PoisonChance, self.base_blaster.poison_chance);
+
+        manual_fire.period = stat(StatType::ProjectileFirePeriod, self.base_manual_fire.period);
+        manual_fire.charge_time =
+            optional_stat(StatType::ChargeTime, self.base_manual_fire.charge_time);
+
+        motion2d.max_speed.x = stat(StatType::MaximumSpeed, self.base_motion2d.max_speed.x);
+        motion2d.max_speed.y = stat(StatType::MaximumSpeed, self.base_motion2d.max_speed.y);
+        motion2d.acceleration.x = stat(StatType::Acceleration, self.base_motion2d.acceleration.x);
+        motion2d.acceleration.y = stat(StatType::Acceleration, self.base_motion2d.acceleration.y);
+        motion2d.deceleration.x = stat(StatType::Deceleration, self.base_motion2d.deceleration.x);
+        motion2d.deceleration.y = stat(StatType::Deceleration, self.base_motion2d.deceleration.y);
+
+        health.max_value = stat(StatType::MaximumHealth, self.base_max_health);
+        health.constrain();
+
+        barrel_roll_ability.execute_cooldown = stat(
+            StatType::AbilityCooldown,
+            self.base_barrel_roll_ability.execute_cooldown,
+        );
+        barrel_roll_ability.steel_barrel = self.base_barrel_roll_ability.steel_barrel;
+        barrel_roll_ability.blast_immune = self.base_barrel_roll_ability.blast_immune;
+        barrel_roll_ability.reflect_blasts = self.base_barrel_roll_ability.reflect_blasts;
+
+        for (category, attract_data) in attractor.attracted_spawnables.iter_mut() {
+            let base_attract_data = &self.base_attractor.attracted_spawnables[category];
+            let (radius_stat, acceleration_stat) = match category {
+                AttractorCategory::Consumable => (
+                    StatType::ConsumableAttractorRadius,
+                    StatType::ConsumableAttractorAcceleration,
+                ),
+                AttractorCategory::Item => (
+                    StatType::ItemAttractorRadius,
+                    StatType::ItemAttractorAcceleration,
+                ),
+                AttractorCategory::Blast => (
+                    StatType::BlastAttractorRadius,
+                    StatType::BlastAttractorAcceleration,
+                ),
+                AttractorCategory::Effect | AttractorCategory::Mob => continue,
+            };
+
+            attract_data.radius = stat(radius_stat, base_attract_data.radius);
+            attract_data.acceleration = stat(acceleration_stat, base_attract_data.acceleration);
+            attract_data.should_repel = base_attract_data.should_repel;
+            attract_data.is_active = base_attract_data.is_active;
+        }
+
+        // flags are set by the most recently collected modifier
+        for modifier in self.modifiers() {
+            match modifier {
+                Modifier::BarrelImmunity(val) => barrel_roll_ability.steel_barrel = *val,
+                Modifier::BarrelBlastImmunity(val) => barrel_roll_ability.blast_immune = *val,
+                Modifier::BarrelBlastReflect(val) => barrel_roll_ability.reflect_blasts = *val,
+                Modifier::BlastAttractorIsActive(val) => {
+                    if let Some(blast_attract_data) = attractor
+                        .attracted_spawnables
+                        .get_mut(&AttractorCategory::Blast)
+                    {
+                        blast_attract_data.is_active = *val;
+                    }
+                }
+                Modifier::BlastAttractorShouldRepel(val) => {
+                    if let Some(blast_attract_data) = attractor
+                        .attracted_spawnables
+                        .get_mut(&AttractorCategory::Blast)
+                    {
+                        blast_attract_data.should_repel = *val;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use amethyst::config::Config;
+
+    use crate::{
+        resources::PlayersResource,
+        spawnable::resources::{StatClamp, StatClampsResource},
+    };
+
+    /// Stat sheet of a character from the game's player data without any modifiers
+    fn juggernaut_stat_sheet() -> StatSheetComponent {
+        let players = <PlayersResource as Config>::load_bytes(include_bytes!(
+            "../../../thetawave_game/src/data_include/data/players.ron"
+        ))
+        .expect("failed to load players.ron");
+        let player_data = &players["juggernaut"];
+
+        StatSheetComponent::new(
+            &player_data.blaster_component,
+            &player_data.manual_fire_component,
+            &player_data.motion2d_component,
+            &player_data.health_component,
+            &player_data.barrel_roll_ability_component,
+            &player_data.attractor_component,
+            &player_data.player_component,
+        )
+    }
+
+    #[test]
+    fn test_stat_value_adds_before_multiplying() {
+        let stat_clamps = StatClampsResource::new();
+
+        // multiplier collected first
+        let mut stat_sheet = juggernaut_stat_sheet();
+        stat_sheet.add_modifiers(
+            SpawnableType::Item(ItemType::EnhancedPlating),
+            vec![Modifier::StatMultiplier(StatType::MaximumHealth, 2.0)],
+        );
+        stat_sheet.add_modifiers(
+            SpawnableType::Item(ItemType::StructureReinforcement),
+            vec![Modifier::MaximumHealth(100.0)],
+        );
+        assert_eq!(
+            stat_sheet.stat_value(StatType::MaximumHealth, 400.0, &stat_clamps),
+            1000.0
+        );
+
+        // addition collected first
+        let mut stat_sheet = juggernaut_stat_sheet();
+        stat_sheet.add_modifiers(
+            SpawnableType::Item(ItemType::StructureReinforcement),
+            vec![Modifier::MaximumHealth(100.0)],
+        );
+        stat_sheet.add_modifiers(
+            SpawnableType::Item(ItemType::EnhancedPlating),
+            vec![Modifier::StatMultiplier(StatType::MaximumHealth, 2.0)],
+        );
+        assert_eq!(
+            stat_sheet.stat_value(StatType::MaximumHealth, 400.0, &stat_clamps),
+            1000.0
+        );
+    }
+
+    #[test]
+    fn test_stat_value_multipliers_stack() {
+        let mut stat_sheet = juggernaut_stat_sheet();
+        for _ in 0..2 {
+            stat_sheet.add_modifiers(
+                SpawnableType::Item(ItemType::BlasterSizeEnhancer),
+                vec![Modifier::StatMultiplier(StatType::ProjectileSize, 1.5)],
+            );
+        }
+
+        assert_eq!(
+            stat_sheet.stat_value(StatType::ProjectileSize, 100.0, &StatClampsResource::new()),
+            225.0
+        );
+
+        // removing one copy only removes its multiplier
+        stat_sheet.remove_modifiers(&SpawnableType::Item(ItemType::BlasterSizeEnhancer));
+        assert_eq!(
+            stat_sheet.stat_value(StatType::ProjectileSize, 100.0, &StatClampsResource::new()),
+            150.0
+        );
+    }
+
+    #[test]
+    fn test_stat_clamp_bounds() {
+        let clamp = StatClamp {
+            min: Some(0.05),
+            max: Some(2.0),
+        };
+        assert_eq!(clamp.apply(-1.0), 0.05);
+        assert_eq!(clamp.apply(1.0), 1.0);
+        assert_eq!(clamp.apply(3.0), 2.0);
+
+        let unbounded = StatClamp {
+            min: None,
+            max: None,
+        };
+        assert_eq!(unbounded.apply(-1.0), -1.0);
+        assert_eq!(unbounded.apply(3.0), 3.0);
+
+        // clamps apply after every modifier
+        let mut stat_clamps = StatClampsResource::new();
+        stat_clamps.insert(
+            StatType::ProjectileFirePeriod,
+            StatClamp {
+                min: Some(0.05),
+                max: None,
+            },
+        );
+        let mut stat_sheet = juggernaut_stat_sheet();
+        stat_sheet.add_modifiers(
+            SpawnableType::Item(ItemType::FrequencyAugmentor),
+            vec![Modifier::ProjectileFirePeriod(-0.5)],
+        );
+        assert_eq!(
+            stat_sheet.stat_value(StatType::ProjectileFirePeriod, 0.3, &stat_clamps),
+            0.05
+        );
+    }
+}
@@ -30,7 +30,6 @@ pub const CRIT_BLAST_SPRITE_INDEX: usize = 2;
 pub const POISON_BLAST_SPRITE_INDEX: usize = 3;
 pub const SIDE_PANEL_LEFT_SPRITE_INDEX: usize = 0;
 pub const SIDE_PANEL_RIGHT_SPRITE_INDEX: usize = 1;
-pub const SPACESHIP_BLAST_SPRITE_INDEX: usize = 0;
 
 // dimensions
 pub const BLAST_HITBOX_DIAMETER: f32 = 2.0;
@@ -39,22 +38,40 @@ pub const SIDE_PANEL_WIDTH: f32 = 45.0;
 // offsets
 pub const ITEM_SPAWN_Y_OFFSET: f32 = 20.0;
 pub const SPAWNER_Y_OFFSET: f32 = 20.0;
+pub const ELITE_SPLIT_SPACING: f32 = 16.0;
 
-// player starting stats
-pub const SPACESHIP_HITBOX_HEIGHT: f32 = 13.0;
-pub const SPACESHIP_HITBOX_WIDTH: f32 = 6.0;
-pub const SPACESHIP_ACCELERATION_X: f32 = 2.0;
-pub const SPACESHIP_DECELERATION_X: f32 = 1.7;
-pub const SPACESHIP_ACCELERATION_Y: f32 = 3.0;
-pub const SPACESHIP_DECELERATION_Y: f32 = 2.5;
-pub const SPACESHIP_MAX_SPEED: f32 = 70.0;
-pub const SPACESHIP_MAX_KNOCKBACK_SPEED: f32 = 100.0;
-pub const SPACESHIP_FIRE_SPEED: f32 = 0.3;
-pub const SPACESHIP_DAMAGE: f32 = 40.0;
-pub const SPACESHIP_BARREL_COOLDOWN: f32 = 2.0;
-pub const SPACESHIP_BARREL_SPEED: f32 = 180.0;
-pub const SPACESHIP_BARREL_DURATION: f32 = 0.3;
-pub const SPACESHIP_HEALTH: f32 = 400.0;
+// bombs
+pub const SPACESHIP_BOMB_DAMAGE: f32 = 100.0;
+pub const SPACESHIP_BOMB_INVULNERABILITY_DURATION: f32 = 1.5;
+pub const BOMB_FLASH_SCALE: f32 = 8.0;
+
+// store
+pub const SOLD_ITEM_STOCK_PROBABILITY: f32 = 1.0;
+pub const STORE_ICON_X: f32 = ARENA_MAX_X + 12.0;
+pub const STORE_ICONS_TOP_Y: f32 = ARENA_MIN_Y + 72.0;
+pub const STORE_ICONS_BOTTOM_Y: f32 = ARENA_MIN_Y + 34.0;
+pub const ITEM_PRICES_TOP_Y: f32 = 130.0;
+pub const ITEM_PRICES_BOTTOM_Y: f32 = 50.0;
+
+// save profile
+pub const SAVE_PROFILE_DIRECTORY: &str = "thetawave";
+pub const SAVE_PROFILE_FILE: &str = "profile.ron";
+pub const SAVE_PROFILE_BACKUP_EXTENSION: &str = "ron.bak";
+
+// lives
+pub const SPACESHIP_RESPAWN_INVULNERABILITY_DURATION: f32 = 3.0;
+pub const SPACESHIP_INVULNERABILITY_FLASH_RATE: f32 = 12.0;
+
+// buffs
+pub const BUFF_ICON_SLOTS: usize = 4;
+pub const BUFF_ICON_X: f32 = ARENA_MIN_X - 10.0;
+pub const BUFF_ICON_Y: f32 = ARENA_MIN_Y + 240.0;
+pub const BUFF_ICON_SPACING: f32 = 18.0;
+// y of the side panel icons level with side panel text anchored to the middle at a y of 0
+pub const SIDE_PANEL_TEXT_ORIGIN_Y: f32 = ARENA_MIN_Y + 110.0;
+
+// announcements
+pub const ANNOUNCEMENT_DURATION: f32 = 3.0;
 
 // status bar
 pub const HEALTH_BAR_X: f32 = 332.0;
@@ -69,3 +86,6 @@ pub const ROLL_BAR_LIMIT: f32 = 28.0;
 pub const RESTOCK_BAR_X: f32 = 324.0;
 pub const RESTOCK_BAR_Y: f32 = 90.0;
 pub const RESTOCK_BAR_LIMIT: f32 = 28.0;
+// player two's health and roll bars mirror player one's on the left side panel
+pub const P2_HEALTH_BAR_X: f32 = GAME_WIDTH - HEALTH_BAR_X;
+pub const P2_ROLL_BAR_X: f32 = GAME_WIDTH - ROLL_BAR_X - ROLL_BAR_LIMIT;
@@ -56,6 +56,10 @@ pub enum ConsumableType {
     Money5,
     HealthWrench,
     Armor,
+    Bomb,
+    Overclock,
+    Magnet,
+    Ammo,
 }
 
 #[derive(Clone, Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
@@ -75,6 +79,12 @@ pub enum ItemType {
     FrequencyAugmentor,
     TractorBeam,
     BlastRepeller,
+    MissileLauncher,
+    BombLauncher,
+    AmmoDrum,
+    CargoBay,
+    ChargeCapacitor,
+    BeamEmitter,
 }
 #[derive(Clone, Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
 pub enum EffectType {
@@ -83,6 +93,7 @@ pub enum EffectType {
     PoisonBlastExplosion,
     CriticalBlastExplosion,
     MobExplosion,
+    BombFlash,
     Star, //TODO: implement background stars
     Giblets(MobType),
 }
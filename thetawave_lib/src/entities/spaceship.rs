@@ -1,159 +1,86 @@
 use crate::{
-    components::AttractorCategory,
-    components::{
-        AbilityDirection, AttractData, AttractorComponent, BarrelRollAbilityComponent,
-        HealthComponent,
-    },
-    constants::{
-        ARENA_HEIGHT, ARENA_MIN_X, ARENA_MIN_Y, ARENA_WIDTH, CRIT_BLAST_SPRITE_INDEX,
-        POISON_BLAST_SPRITE_INDEX, SPACESHIP_ACCELERATION_X, SPACESHIP_ACCELERATION_Y,
-        SPACESHIP_BARREL_COOLDOWN, SPACESHIP_BARREL_DURATION, SPACESHIP_BARREL_SPEED,
-        SPACESHIP_BLAST_SPRITE_INDEX, SPACESHIP_DAMAGE, SPACESHIP_DECELERATION_X,
-        SPACESHIP_DECELERATION_Y, SPACESHIP_FIRE_SPEED, SPACESHIP_HEALTH, SPACESHIP_HITBOX_HEIGHT,
-        SPACESHIP_HITBOX_WIDTH, SPACESHIP_MAX_KNOCKBACK_SPEED, SPACESHIP_MAX_SPEED,
-    },
-    motion::components::{Hitbox2DComponent, Motion2DComponent},
-    resources::PlayersResource,
-    weapons::{
-        components::{BlasterComponent, ManualFireComponent},
-        BlastType,
-    },
+    components::StatSheetComponent,
+    constants::{ARENA_HEIGHT, ARENA_MIN_X, ARENA_MIN_Y, ARENA_WIDTH},
+    events::ItemGetEvent,
+    resources::{PlayersResource, SpriteSheetsResource},
+    weapons::components::SecondaryWeaponComponent,
 };
 use amethyst::{
-    assets::Handle,
-    core::{math::Vector2, transform::Transform},
+    core::transform::Transform,
     ecs::{World, WorldExt},
     prelude::Builder,
-    renderer::{SpriteRender, SpriteSheet, Transparent},
+    renderer::{SpriteRender, Transparent},
+    shrev::EventChannel,
 };
-use std::collections::HashMap;
 
-pub fn initialize_spaceship(world: &mut World, sprite_sheet_handle: Handle<SpriteSheet>) {
-    let player = {
-        let players_resource = world.read_resource::<PlayersResource>();
-        players_resource["juggernaut"].player_component.clone()
-    };
+/// Create the spaceship of the given character from its data in players.ron
+pub fn initialize_spaceship(
+    world: &mut World,
+    character: &str,
+    player_index: usize,
+    player_count: usize,
+) {
+    let mut player_data = world.read_resource::<PlayersResource>()[character].clone();
+    player_data.player_component.player_index = player_index;
 
+    // spread players evenly across the arena
     let mut local_transform = Transform::default();
     local_transform.set_translation_xyz(
-        ARENA_MIN_X + (ARENA_WIDTH / 2.0),
+        ARENA_MIN_X + (ARENA_WIDTH * (player_index + 1) as f32 / (player_count + 1) as f32),
         ARENA_MIN_Y + (ARENA_HEIGHT / 6.0),
         0.9,
     );
 
     let sprite_render = SpriteRender {
-        sprite_sheet: sprite_sheet_handle,
-        sprite_number: 0,
-    };
-
-    let mut blast_sprite_indicies = HashMap::new();
-    blast_sprite_indicies.insert("normal".to_string(), SPACESHIP_BLAST_SPRITE_INDEX);
-    blast_sprite_indicies.insert("crit".to_string(), CRIT_BLAST_SPRITE_INDEX);
-    blast_sprite_indicies.insert("poison".to_string(), POISON_BLAST_SPRITE_INDEX);
-
-    let hitbox = Hitbox2DComponent {
-        width: SPACESHIP_HITBOX_WIDTH,
-        height: SPACESHIP_HITBOX_HEIGHT,
-        offset: Vector2::new(0.0, 0.0),
-        offset_rotation: 0.0,
-    };
-
-    let motion_2d = Motion2DComponent {
-        velocity: Vector2::new(0.0, 0.0),
-        acceleration: Vector2::new(SPACESHIP_ACCELERATION_X, SPACESHIP_ACCELERATION_Y),
-        deceleration: Vector2::new(SPACESHIP_DECELERATION_X, SPACESHIP_DECELERATION_Y),
-        angular_velocity: 0.0,
-        angular_acceleration: 0.0,
-        angular_deceleration: 0.0,
-        angular_speed: 0.0,
-        speed: Vector2::new(SPACESHIP_MAX_SPEED, SPACESHIP_MAX_SPEED),
-        max_speed: Vector2::new(SPACESHIP_MAX_KNOCKBACK_SPEED, SPACESHIP_MAX_KNOCKBACK_SPEED),
-        immovable: false,
-        target_position: None,
-    };
-
-    let blaster = BlasterComponent {
-        count: 1,
-        blast_type: BlastType::Ally,
-        shot_velocity: Vector2::new(0.0, 100.0),
-        velocity_multiplier: 0.5,
-        offset: Vector2::new(0.0, 9.0),
-        damage: SPACESHIP_DAMAGE,
-        poison_damage: 0.0,
-        poison_chance: 0.0,
-        crit_chance: 0.0,
-        size_multiplier: 1.0,
-        spacing: 7.0,
-    };
-
-    let manual_fire = ManualFireComponent {
-        period: SPACESHIP_FIRE_SPEED,
-        timer: 0.0,
-        ready: false,
-    };
-
-    let health = HealthComponent {
-        value: SPACESHIP_HEALTH,
-        max_value: SPACESHIP_HEALTH,
-        armor: 0,
-    };
-
-    let barrel_roll_ability = BarrelRollAbilityComponent {
-        execute_cooldown: SPACESHIP_BARREL_COOLDOWN,
-        execute_timer: 0.0,
-        action_cooldown: SPACESHIP_BARREL_DURATION,
-        action_timer: 0.0,
-        action_direction: AbilityDirection::None,
-        speed: SPACESHIP_BARREL_SPEED,
-        steel_barrel: false,
+        sprite_sheet: world.read_resource::<SpriteSheetsResource>().spritesheets
+            [&player_data.sprite_render_data.spritesheet]
+            .clone(),
+        sprite_number: player_data.sprite_render_data.initial_index,
     };
 
-    // Attach spawnables affected by the player's AttractorData component.
-    let mut attracted_spawnables = HashMap::new();
-    attracted_spawnables.insert(
-        AttractorCategory::Consumable,
-        AttractData {
-            radius: 20.0,
-            acceleration: 0.3,
-            should_repel: false,
-            is_active: true,
-        },
-    );
-    attracted_spawnables.insert(
-        AttractorCategory::Item,
-        AttractData {
-            radius: 18.0,
-            acceleration: 0.3,
-            should_repel: false,
-            is_active: true,
-        },
-    );
-    attracted_spawnables.insert(
-        AttractorCategory::Blast,
-        AttractData {
-            radius: 3.0,
-            acceleration: 0.0,
-            should_repel: true,
-            is_active: false,
-        },
+    // components are given their stats by modifiers relative to these base stats
+    let stat_sheet = StatSheetComponent::new(
+        &player_data.blaster_component,
+        &player_data.manual_fire_component,
+        &player_data.motion2d_component,
+        &player_data.health_component,
+        &player_data.barrel_roll_ability_component,
+        &player_data.attractor_component,
+        &player_data.player_component,
     );
 
-    let attractor = AttractorComponent {
-        attracted_spawnables,
-    };
-
-    world
+    let mut spaceship = world
         .create_entity()
         .with(sprite_render)
-        .with(barrel_roll_ability)
-        .with(blaster)
-        .with(manual_fire)
-        .with(hitbox)
-        .with(motion_2d)
-        .with(health)
+        .with(player_data.barrel_roll_ability_component)
+        .with(player_data.blaster_component)
+        .with(player_data.manual_fire_component)
+        .with(SecondaryWeaponComponent::default())
+        .with(player_data.hitbox_component)
+        .with(player_data.motion2d_component)
+        .with(player_data.health_component)
         .with(local_transform)
         .with(Transparent)
-        .with(player)
-        .with(attractor)
-        .build();
+        .with(player_data.player_component)
+        .with(player_data.attractor_component)
+        .with(stat_sheet);
+
+    if let Some(beam) = player_data.beam_component {
+        spaceship = spaceship.with(beam);
+    }
+
+    if let Some(special_ability) = player_data.special_ability_component {
+        spaceship = spaceship.with(special_ability);
+    }
+
+    let spaceship_entity = spaceship.build();
+
+    // starting items are applied through the same modifiers as collected items
+    let mut item_get_event_channel = world.write_resource::<EventChannel<ItemGetEvent>>();
+    for item_type in player_data.starting_items {
+        item_get_event_channel.single_write(ItemGetEvent {
+            player_entity: spaceship_entity,
+            item_type,
+        });
+    }
 }
@@ -103,6 +103,13 @@ pub struct ItemGetEvent {
     pub item_type: ItemType,
 }
 
+/// Sent when an item is taken away from a player
+#[derive(Debug)]
+pub struct ItemRemoveEvent {
+    pub player_entity: Entity,
+    pub item_type: ItemType,
+}
+
 #[derive(Debug)]
 pub struct ConsumableGetEvent {
     pub player_entity: Entity,
@@ -111,12 +118,13 @@ pub struct ConsumableGetEvent {
 
 #[derive(Debug)]
 pub struct MobReachedBottomEvent {
+    pub mob: Entity,
     pub damage: f32,
 }
 
 impl MobReachedBottomEvent {
-    pub fn new(damage: f32) -> MobReachedBottomEvent {
-        MobReachedBottomEvent { damage }
+    pub fn new(mob: Entity, damage: f32) -> MobReachedBottomEvent {
+        MobReachedBottomEvent { mob, damage }
     }
 }
 
@@ -130,3 +138,9 @@ pub struct AttractionEvent {
 pub struct PlayAudioEvent {
     pub source: SourceHandle,
 }
+
+/// Sent to show a message on screen
+#[derive(Debug)]
+pub struct AnnouncementEvent {
+    pub text: String,
+}
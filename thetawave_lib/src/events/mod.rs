@@ -1,7 +1,7 @@
 mod events;
 
 pub use self::events::{
-    ArenaBorderCollisionEvent, AttractionEvent, CollisionEvent, ConsumableGetEvent, ItemGetEvent,
-    MobCollisionEvent, MobDestroyedEvent, MobReachedBottomEvent, PlayAudioEvent,
-    PlayerCollisionEvent,
+    AnnouncementEvent, ArenaBorderCollisionEvent, AttractionEvent, CollisionEvent,
+    ConsumableGetEvent, ItemGetEvent, ItemRemoveEvent, MobCollisionEvent, MobDestroyedEvent,
+    MobReachedBottomEvent, PlayAudioEvent, PlayerCollisionEvent,
 };
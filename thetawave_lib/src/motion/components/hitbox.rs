@@ -60,69 +60,46 @@ impl Hitbox2DComponent {
                 && (y1 + self.height) > y2;
         }
 
-        // Step 1: find coordinates of all four corners of each hitbox centered at origin
-        let a_ur_x_temp = self.width / 2.0;
-        let a_ur_y_temp = self.height / 2.0;
-        let a_ul_x_temp = -self.width / 2.0;
-        let a_ul_y_temp = self.height / 2.0;
-        let a_lr_x_temp = self.width / 2.0;
-        let a_lr_y_temp = -self.height / 2.0;
-        let a_ll_x_temp = -self.width / 2.0;
-        let a_ll_y_temp = -self.height / 2.0;
+        // run separating axis theorem
+        sat_is_colliding(
+            &self.corners(transform_a),
+            &hitbox_b.corners(transform_b),
+            &None,
+        )
+    }
 
-        let b_ur_x_temp = hitbox_b.width / 2.0;
-        let b_ur_y_temp = hitbox_b.height / 2.0;
-        let b_ul_x_temp = -hitbox_b.width / 2.0;
-        let b_ul_y_temp = hitbox_b.height / 2.0;
-        let b_lr_x_temp = hitbox_b.width / 2.0;
-        let b_lr_y_temp = -hitbox_b.height / 2.0;
-        let b_ll_x_temp = -hitbox_b.width / 2.0;
-        let b_ll_y_temp = -hitbox_b.height / 2.0;
+    /// Find rotated coordinates of all four corners of the hitbox in the arena (in winding order)
+    pub fn corners(&self, transform: &Transform) -> [Vector; 4] {
+        // Step 1: find coordinates of all four corners of the hitbox centered at origin
+        let ur_x_temp = self.width / 2.0;
+        let ur_y_temp = self.height / 2.0;
+        let ul_x_temp = -self.width / 2.0;
+        let ul_y_temp = self.height / 2.0;
+        let lr_x_temp = self.width / 2.0;
+        let lr_y_temp = -self.height / 2.0;
+        let ll_x_temp = -self.width / 2.0;
+        let ll_y_temp = -self.height / 2.0;
 
-        // Step 2: find rotated coordinates of four corners
-        let a_x_offset = transform_a.translation().x + self.offset.x;
-        let a_y_offset = transform_a.translation().y + self.offset.y;
-        let rotated_hitbox_1 = [
-            Vector(
-                rotate_x(a_ur_x_temp, a_ur_y_temp, self.offset_rotation) + a_x_offset,
-                rotate_y(a_ur_x_temp, a_ur_y_temp, self.offset_rotation) + a_y_offset,
-            ),
+        // Step 2: find rotated coordinates of four corners in winding order
+        let x_offset = transform.translation().x + self.offset.x;
+        let y_offset = transform.translation().y + self.offset.y;
+        [
             Vector(
-                rotate_x(a_ul_x_temp, a_ul_y_temp, self.offset_rotation) + a_x_offset,
-                rotate_y(a_ul_x_temp, a_ul_y_temp, self.offset_rotation) + a_y_offset,
+                rotate_x(ur_x_temp, ur_y_temp, self.offset_rotation) + x_offset,
+                rotate_y(ur_x_temp, ur_y_temp, self.offset_rotation) + y_offset,
             ),
             Vector(
-                rotate_x(a_lr_x_temp, a_lr_y_temp, self.offset_rotation) + a_x_offset,
-                rotate_y(a_lr_x_temp, a_lr_y_temp, self.offset_rotation) + a_y_offset,
+                rotate_x(ul_x_temp, ul_y_temp, self.offset_rotation) + x_offset,
+                rotate_y(ul_x_temp, ul_y_temp, self.offset_rotation) + y_offset,
             ),
             Vector(
-                rotate_x(a_ll_x_temp, a_ll_y_temp, self.offset_rotation) + a_x_offset,
-                rotate_y(a_ll_x_temp, a_ll_y_temp, self.offset_rotation) + a_y_offset,
-            ),
-        ];
-
-        let b_x_offset = transform_b.translation().x + hitbox_b.offset.x;
-        let b_y_offset = transform_b.translation().y + hitbox_b.offset.y;
-        let rotated_hitbox_2 = [
-            Vector(
-                rotate_x(b_ur_x_temp, b_ur_y_temp, hitbox_b.offset_rotation) + b_x_offset,
-                rotate_y(b_ur_x_temp, b_ur_y_temp, hitbox_b.offset_rotation) + b_y_offset,
+                rotate_x(ll_x_temp, ll_y_temp, self.offset_rotation) + x_offset,
+                rotate_y(ll_x_temp, ll_y_temp, self.offset_rotation) + y_offset,
             ),
             Vector(
-                rotate_x(b_ul_x_temp, b_ul_y_temp, hitbox_b.offset_rotation) + b_x_offset,
-                rotate_y(b_ul_x_temp, b_ul_y_temp, hitbox_b.offset_rotation) + b_y_offset,
+                rotate_x(lr_x_temp, lr_y_temp, self.offset_rotation) + x_offset,
+                rotate_y(lr_x_temp, lr_y_temp, self.offset_rotation) + y_offset,
             ),
-            Vector(
-                rotate_x(b_lr_x_temp, b_lr_y_temp, hitbox_b.offset_rotation) + b_x_offset,
-                rotate_y(b_lr_x_temp, b_lr_y_temp, hitbox_b.offset_rotation) + b_y_offset,
-            ),
-            Vector(
-                rotate_x(b_ll_x_temp, b_ll_y_temp, hitbox_b.offset_rotation) + b_x_offset,
-                rotate_y(b_ll_x_temp, b_ll_y_temp, hitbox_b.offset_rotation) + b_y_offset,
-            ),
-        ];
-
-        // run separating axis theorem
-        sat_is_colliding(&rotated_hitbox_1, &rotated_hitbox_2, &None)
+        ]
     }
 }
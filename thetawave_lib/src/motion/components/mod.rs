@@ -2,5 +2,10 @@
 
 mod hitbox;
 mod motion;
+mod target;
 
-pub use self::{hitbox::Hitbox2DComponent, motion::Motion2DComponent};
+pub use self::{
+    hitbox::Hitbox2DComponent,
+    motion::Motion2DComponent,
+    target::{TargetCandidate, TargetComponent, TargetSelector},
+};
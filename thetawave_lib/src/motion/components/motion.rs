@@ -78,8 +78,14 @@ impl Motion2DComponent {
         }
     }
 
-    /// Accelerate to face `target_position`
-    pub fn turn_towards_target(&mut self, current_position: Vector2<f32>, current_angle: f32) {
+    /// Rotate to face `target_position` at `turn_rate` radians per second without turning past it
+    pub fn turn_towards_target(
+        &mut self,
+        current_position: Vector2<f32>,
+        current_angle: f32,
+        turn_rate: f32,
+        delta_time: f32,
+    ) {
         if let Some(target_position) = self.target_position {
             let target_angle = (current_position.y - target_position.y)
                 .atan2(current_position.x - target_position.x)
@@ -91,14 +97,46 @@ impl Motion2DComponent {
             let smallest_angle =
                 signed_modulo(target_angle - adjusted_angle + 180.0, 360.0) - 180.0;
 
+            let remaining_angle = smallest_angle.abs().to_radians();
+            let angular_speed = if remaining_angle < turn_rate * delta_time {
+                remaining_angle / delta_time
+            } else {
+                turn_rate
+            };
+
             if smallest_angle >= 0.0 {
-                self.angular_velocity -= self.angular_acceleration;
+                self.angular_velocity = -angular_speed;
             } else {
-                self.angular_velocity += self.angular_acceleration;
+                self.angular_velocity = angular_speed;
             }
         }
     }
 
+    /// Rotate velocity towards `target_position` by at most `turn_rate` radians per second
+    pub fn steer_towards_target(
+        &mut self,
+        current_position: Vector2<f32>,
+        turn_rate: f32,
+        delta_time: f32,
+    ) {
+        if let Some(target_position) = self.target_position {
+            let target_angle = (target_position.y - current_position.y)
+                .atan2(target_position.x - current_position.x);
+            let current_angle = self.velocity.y.atan2(self.velocity.x);
+
+            let smallest_angle = signed_modulo(
+                target_angle - current_angle + std::f32::consts::PI,
+                2.0 * std::f32::consts::PI,
+            ) - std::f32::consts::PI;
+
+            let max_turn = turn_rate * delta_time;
+            let new_angle = current_angle + smallest_angle.max(-max_turn).min(max_turn);
+            let speed = self.velocity.norm();
+
+            self.velocity = Vector2::new(speed * new_angle.cos(), speed * new_angle.sin());
+        }
+    }
+
     /// Accelerate towards `target_position` (accelerate away if `should_repel` is true)
     pub fn move_towards_target(
         &mut self,
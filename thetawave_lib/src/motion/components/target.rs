@@ -0,0 +1,122 @@
+use crate::tools::distance;
+use amethyst::{
+    core::math::Vector2,
+    ecs::prelude::{Component, DenseVecStorage},
+};
+use serde::{Deserialize, Serialize};
+
+/// Used for choosing what an entity targets
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub enum TargetSelector {
+    /// Closest player to the entity
+    NearestPlayer,
+    /// Closest ally mob to the entity
+    NearestAlly,
+    /// Ally mob with the lowest proportion of health remaining
+    LowestHealthAlly,
+    /// Closest enemy mob to the entity
+    NearestEnemy,
+    /// Fixed position in the arena
+    FixedPoint(Vector2<f32>),
+}
+
+/// Position and proportion of health remaining of a targetable entity
+#[derive(Clone, Debug)]
+pub struct TargetCandidate {
+    /// Position of the candidate
+    pub position: Vector2<f32>,
+    /// Proportion of health remaining of the candidate
+    pub health_ratio: f32,
+}
+
+/// Used for homing in on a target
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct TargetComponent {
+    /// Selector for choosing a target
+    pub selector: TargetSelector,
+    /// Time in seconds between choosing a new target
+    pub retarget_period: f32,
+    /// Rate at which the entity turns towards its target in radians per second
+    pub turn_rate: f32,
+    /// Selector tried before `selector`, set by phases with a preferred target
+    #[serde(default)]
+    pub preferred_selector: Option<TargetSelector>,
+    /// Stores current time until choosing a new target
+    #[serde(default)]
+    timer: f32,
+}
+
+impl Component for TargetComponent {
+    type Storage = DenseVecStorage<Self>;
+}
+
+impl TargetComponent {
+    /// Update timer and return true when a new target should be chosen (call every frame)
+    pub fn should_retarget(&mut self, delta_time: f32) -> bool {
+        self.timer -= delta_time;
+
+        if self.timer <= 0.0 {
+            self.timer = self.retarget_period;
+            return true;
+        }
+
+        false
+    }
+
+    /// Choose a target position from the candidates of each category,
+    /// falling back to `selector` when the preferred selector finds no target
+    pub fn select_target(
+        &self,
+        current_position: Vector2<f32>,
+        players: &[TargetCandidate],
+        allies: &[TargetCandidate],
+        enemies: &[TargetCandidate],
+    ) -> Option<Vector2<f32>> {
+        self.preferred_selector
+            .as_ref()
+            .and_then(|selector| selector.select(current_position, players, allies, enemies))
+            .or_else(|| {
+                self.selector
+                    .select(current_position, players, allies, enemies)
+            })
+    }
+}
+
+impl TargetSelector {
+    /// Choose a target position from the candidates of each category
+    pub fn select(
+        &self,
+        current_position: Vector2<f32>,
+        players: &[TargetCandidate],
+        allies: &[TargetCandidate],
+        enemies: &[TargetCandidate],
+    ) -> Option<Vector2<f32>> {
+        match self {
+            TargetSelector::NearestPlayer => nearest(current_position, players),
+            TargetSelector::NearestAlly => nearest(current_position, allies),
+            TargetSelector::NearestEnemy => nearest(current_position, enemies),
+            TargetSelector::LowestHealthAlly => allies
+                .iter()
+                .min_by(|a, b| a.health_ratio.total_cmp(&b.health_ratio))
+                .map(|candidate| candidate.position),
+            TargetSelector::FixedPoint(position) => Some(*position),
+        }
+    }
+}
+
+/// Position of the closest candidate to the current position
+fn nearest(current_position: Vector2<f32>, candidates: &[TargetCandidate]) -> Option<Vector2<f32>> {
+    let candidate_distance = |candidate: &TargetCandidate| {
+        distance(
+            candidate.position.x,
+            current_position.x,
+            candidate.position.y,
+            current_position.y,
+        )
+    };
+
+    candidates
+        .iter()
+        .min_by(|a, b| candidate_distance(a).total_cmp(&candidate_distance(b)))
+        .map(|candidate| candidate.position)
+}
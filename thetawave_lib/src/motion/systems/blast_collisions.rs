@@ -0,0 +1,82 @@
+use crate::{
+    components::{BarrierComponent, PushDirection},
+    events::ArenaBorderCollisionEvent,
+    motion::components::Motion2DComponent,
+    spawnable::components::BlastComponent,
+};
+use amethyst::{
+    ecs::*,
+    ecs::{Read, System, World},
+    shrev::{EventChannel, ReaderId},
+};
+
+/// Handles bouncing of blasts off of arena borders
+#[derive(Default)]
+pub struct BlastArenaBorderCollisionSystem {
+    /// Reads from the arena border collision event channel
+    event_reader: Option<ReaderId<ArenaBorderCollisionEvent>>,
+}
+
+impl<'s> System<'s> for BlastArenaBorderCollisionSystem {
+    /// Data used by the system
+    type SystemData = (
+        Read<'s, EventChannel<ArenaBorderCollisionEvent>>,
+        ReadStorage<'s, BarrierComponent>,
+        WriteStorage<'s, BlastComponent>,
+        WriteStorage<'s, Motion2DComponent>,
+    );
+
+    /// Sets up event readers
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.event_reader = Some(
+            world
+                .fetch_mut::<EventChannel<ArenaBorderCollisionEvent>>()
+                .register_reader(),
+        );
+    }
+
+    /// System game logic
+    fn run(
+        &mut self,
+        (collision_event_channel, barriers, mut blasts, mut motion_2ds): Self::SystemData,
+    ) {
+        for event in collision_event_channel.read(self.event_reader.as_mut().unwrap()) {
+            if let (Some(barrier), Some(blast), Some(blast_motion)) = (
+                barriers.get(event.arena_border_entity),
+                blasts.get_mut(event.colliding_entity),
+                motion_2ds.get_mut(event.colliding_entity),
+            ) {
+                if blast.bounces == 0 {
+                    continue;
+                }
+
+                // only bounce blasts moving into the barrier
+                let velocity = &mut blast_motion.velocity;
+                let bounced = match barrier.push_direction {
+                    PushDirection::Left if velocity.x > 0.0 => {
+                        velocity.x = -velocity.x;
+                        true
+                    }
+                    PushDirection::Right if velocity.x < 0.0 => {
+                        velocity.x = -velocity.x;
+                        true
+                    }
+                    PushDirection::Down if velocity.y > 0.0 => {
+                        velocity.y = -velocity.y;
+                        true
+                    }
+                    PushDirection::Up if velocity.y < 0.0 => {
+                        velocity.y = -velocity.y;
+                        true
+                    }
+                    _ => false,
+                };
+
+                if bounced {
+                    blast.bounces -= 1;
+                }
+            }
+        }
+    }
+}
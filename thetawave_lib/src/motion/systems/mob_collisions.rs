@@ -1,12 +1,16 @@
 use crate::{
     audio::Sounds,
-    components::{BarrierComponent, HealthComponent, PlayerComponent},
+    components::{BarrierComponent, FactionComponent, HealthComponent, PlayerComponent},
     entities::{EffectType, EnemyType, MobType},
     events::{MobCollisionEvent, PlayAudioEvent},
     motion::components::Motion2DComponent,
     motion::systems::{barrier_collision, immovable_collision, standard_collision},
-    resources::{GameParametersResource, SpriteSheetsResource},
-    spawnable::{components::BlastComponent, components::MobComponent, resources::EffectsResource},
+    resources::{FactionsResource, GameParametersResource, SpriteSheetsResource},
+    spawnable::{
+        components::{BlastComponent, MobComponent},
+        resources::EffectsResource,
+    },
+    tools::distance,
     weapons::BlastType,
 };
 use amethyst::{
@@ -192,7 +196,9 @@ impl<'s> System<'s> for MobBlastCollisionSystem {
         Entities<'s>,
         WriteStorage<'s, HealthComponent>,
         WriteStorage<'s, BlastComponent>,
+        ReadStorage<'s, FactionComponent>,
         ReadStorage<'s, Transform>,
+        ReadExpect<'s, FactionsResource>,
         ReadExpect<'s, EffectsResource>,
         ReadExpect<'s, SpriteSheetsResource>,
         ReadExpect<'s, LazyUpdate>,
@@ -218,7 +224,9 @@ impl<'s> System<'s> for MobBlastCollisionSystem {
             entities,
             mut healths,
             mut blasts,
+            factions,
             transforms,
+            factions_resource,
             effects_resource,
             sprite_resource,
             lazy_update,
@@ -228,39 +236,85 @@ impl<'s> System<'s> for MobBlastCollisionSystem {
     ) {
         for event in mob_collision_event_channel.read(self.event_reader.as_mut().unwrap()) {
             if let Some(blast) = blasts.get_mut(event.colliding_entity) {
-                let mob_health = healths.get_mut(event.mob_entity).unwrap();
                 let blast_transform = transforms.get(event.colliding_entity).unwrap();
 
-                match blast.blast_type {
-                    BlastType::Ally | BlastType::AllyCritical | BlastType::AllyPoison => {
-                        entities
-                            .delete(event.colliding_entity)
-                            .expect("unable to delete entity");
-
-                        play_audio_channel.single_write(PlayAudioEvent {
-                            source: sounds.sound_effects["metal_ping"].clone(),
-                        });
-
-                        effects_resource.spawn_effect(
-                            match blast.blast_type {
-                                BlastType::Ally => &EffectType::AllyBlastExplosion,
-                                BlastType::AllyCritical => &EffectType::CriticalBlastExplosion,
-                                BlastType::AllyPoison => &EffectType::PoisonBlastExplosion,
-                                _ => panic!("unreachable"),
-                            },
-                            blast_transform.clone(),
-                            &sprite_resource,
-                            &entities,
-                            &lazy_update,
-                        );
+                // blasts pass through mobs of factions they do not damage
+                let damage_multiplier = match (
+                    factions.get(event.colliding_entity),
+                    factions.get(event.mob_entity),
+                ) {
+                    (Some(blast_faction), Some(mob_faction)) => factions_resource
+                        .damage_multiplier(&blast_faction.faction, &mob_faction.faction),
+                    _ => 0.0,
+                };
+
+                // piercing blasts only hit each mob once
+                if damage_multiplier <= 0.0 || blast.hit_entities.contains(&event.mob_entity) {
+                    continue;
+                }
 
-                        mob_health.value -= blast.damage;
-                        //TODO: apply poison to enemy health component from blast
-                        //enemy.poison = blast.poison_damage;
-                    }
+                if blast.piercing > 0 {
+                    blast.piercing -= 1;
+                    blast.hit_entities.push(event.mob_entity);
+                } else {
+                    entities
+                        .delete(event.colliding_entity)
+                        .expect("unable to delete entity");
+                }
+
+                play_audio_channel.single_write(PlayAudioEvent {
+                    source: sounds.sound_effects["metal_ping"].clone(),
+                });
 
-                    _ => {}
+                effects_resource.spawn_effect(
+                    match blast.blast_type {
+                        BlastType::Ally => &EffectType::AllyBlastExplosion,
+                        BlastType::AllyCritical => &EffectType::CriticalBlastExplosion,
+                        BlastType::AllyPoison => &EffectType::PoisonBlastExplosion,
+                        BlastType::Enemy => &EffectType::EnemyBlastExplosion,
+                    },
+                    blast_transform.clone(),
+                    &sprite_resource,
+                    &entities,
+                    &lazy_update,
+                );
+
+                // explosive blasts also damage other mobs within the explosion radius
+                if blast.explosion_radius > 0.0 {
+                    let blast_faction = factions.get(event.colliding_entity).unwrap().faction;
+
+                    for (entity, faction, transform, health) in
+                        (&*entities, &factions, &transforms, &mut healths).join()
+                    {
+                        if entity == event.mob_entity {
+                            continue;
+                        }
+
+                        // explosions skip factions they can't damage so armor isn't used up
+                        let explosion_multiplier =
+                            factions_resource.damage_multiplier(&blast_faction, &faction.faction);
+                        if explosion_multiplier <= 0.0 {
+                            continue;
+                        }
+
+                        if distance(
+                            transform.translation().x,
+                            blast_transform.translation().x,
+                            transform.translation().y,
+                            blast_transform.translation().y,
+                        ) < blast.explosion_radius
+                        {
+                            health.take_damage(blast.damage * explosion_multiplier);
+                        }
+                    }
                 }
+
+                healths
+                    .get_mut(event.mob_entity)
+                    .unwrap()
+                    .take_damage(blast.damage * damage_multiplier);
+                //TODO: apply poison to enemy health component from blast
+                //enemy.poison = blast.poison_damage;
             }
         }
     }
@@ -6,6 +6,7 @@ use crate::{
 };
 use amethyst::core::math::Vector2;
 
+mod blast_collisions;
 mod collision_detection;
 mod mob_collisions;
 mod motion2d;
@@ -13,6 +14,7 @@ mod player_collisions;
 mod player_motion2d;
 
 pub use self::{
+    blast_collisions::BlastArenaBorderCollisionSystem,
     collision_detection::{CollisionDetectionSystem, CollisionHandlerSystem},
     mob_collisions::{
         MobArenaBorderCollisionSystem, MobBlastCollisionSystem, MobMobCollisionSystem,
@@ -20,7 +22,7 @@ pub use self::{
     },
     motion2d::{
         BlastMotion2DSystem, ConsumableMotion2DSystem, ItemMotion2DSystem, MobMotion2DSystem,
-        MobTargetSystem, Motion2DSystem,
+        Motion2DSystem, TargetSystem,
     },
     player_collisions::{
         PlayerArenaBorderCollisionSystem, PlayerBlastCollisionSystem,
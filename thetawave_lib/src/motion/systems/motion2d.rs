@@ -1,9 +1,14 @@
 use crate::{
-    components::{AttractorCategory, PlayerComponent},
+    components::{
+        AttractorCategory, CooldownAbility, DecoyComponent, HealthComponent, PlayerComponent,
+        SpecialAbilityComponent, SpecialAbilityType,
+    },
     constants::{ARENA_HEIGHT, ARENA_MIN_Y},
     entities::{AllyType, EnemyType, MobType, NeutralType},
     events::AttractionEvent,
-    motion::components::{Hitbox2DComponent, Motion2DComponent},
+    motion::components::{
+        Hitbox2DComponent, Motion2DComponent, TargetCandidate, TargetComponent,
+    },
     spawnable::components::{BlastComponent, ConsumableComponent, ItemComponent, MobComponent},
     tools::distance,
     weapons::BlastType,
@@ -21,15 +26,64 @@ pub struct Motion2DSystem;
 impl<'s> System<'s> for Motion2DSystem {
     /// Data used by the system
     type SystemData = (
+        Entities<'s>,
         WriteStorage<'s, Motion2DComponent>,
         WriteStorage<'s, Transform>,
+        ReadStorage<'s, PlayerComponent>,
+        ReadStorage<'s, SpecialAbilityComponent>,
         Read<'s, Time>,
     );
 
     /// System game logic
-    fn run(&mut self, (mut motion_2ds, mut transforms, time): Self::SystemData) {
-        for (motion_2d, transform) in (&mut motion_2ds, &mut transforms).join() {
-            let dt = time.delta_seconds();
+    fn run(
+        &mut self,
+        (
+            entities,
+            mut motion_2ds,
+            mut transforms,
+            players,
+            special_abilities,
+            time,
+        ): Self::SystemData,
+    ) {
+        // position, radius, and time scale of every active time slow field
+        let time_slow_fields: Vec<(Vector2<f32>, f32, f32)> =
+            (&players, &special_abilities, &transforms)
+                .join()
+                .filter_map(|(_player, special_ability, transform)| {
+                    match special_ability.ability_type {
+                        SpecialAbilityType::TimeSlow { radius, time_scale }
+                            if special_ability.is_active() =>
+                        {
+                            Some((
+                                Vector2::new(transform.translation().x, transform.translation().y),
+                                radius,
+                                time_scale,
+                            ))
+                        }
+                        _ => None,
+                    }
+                })
+                .collect();
+
+        for (entity, motion_2d, transform) in (&*entities, &mut motion_2ds, &mut transforms).join()
+        {
+            let mut dt = time.delta_seconds();
+
+            // everything other than players moves slower inside time slow fields
+            if players.get(entity).is_none() {
+                for (field_position, radius, time_scale) in time_slow_fields.iter() {
+                    if distance(
+                        transform.translation().x,
+                        field_position.x,
+                        transform.translation().y,
+                        field_position.y,
+                    ) <= *radius
+                    {
+                        dt *= time_scale;
+                    }
+                }
+            }
 
             // update translation based on velocity and delta time
             transform.set_translation_xyz(
@@ -83,8 +137,10 @@ impl<'s> System<'s> for BlastMotion2DSystem {
     type SystemData = (
         WriteStorage<'s, Motion2DComponent>,
         ReadStorage<'s, BlastComponent>,
+        ReadStorage<'s, TargetComponent>,
         WriteStorage<'s, Transform>,
         Read<'s, EventChannel<AttractionEvent>>,
+        Read<'s, Time>,
     );
 
     /// Sets up event readers
@@ -100,7 +156,7 @@ impl<'s> System<'s> for BlastMotion2DSystem {
     /// System game logic
     fn run(
         &mut self,
-        (mut motion_2ds, blasts, mut transforms, attraction_channel): Self::SystemData,
+        (mut motion_2ds, blasts, targets, mut transforms, attraction_channel, time): Self::SystemData,
     ) {
         let mut attracted = false;
         for event in attraction_channel.read(self.event_reader.as_mut().unwrap()) {
@@ -148,10 +204,21 @@ impl<'s> System<'s> for BlastMotion2DSystem {
         }
 
         if !attracted {
-            for (_blast, motion_2d) in (&blasts, &mut motion_2ds).join() {
-                motion_2d.target_position = None;
-                motion_2d.move_down();
-                motion_2d.brake_horizontal();
+            for (_blast, target, motion_2d, transform) in
+                (&blasts, targets.maybe(), &mut motion_2ds, &transforms).join()
+            {
+                // blasts with a target component steer towards their target
+                if let Some(target) = target {
+                    motion_2d.steer_towards_target(
+                        Vector2::new(transform.translation().x, transform.translation().y),
+                        target.turn_rate,
+                        time.delta_seconds(),
+                    );
+                } else {
+                    motion_2d.target_position = None;
+                    motion_2d.move_down();
+                    motion_2d.brake_horizontal();
+                }
             }
         }
     }
@@ -304,71 +371,144 @@ impl<'s> System<'s> for MobMotion2DSystem {
     /// Data used by the system
     type SystemData = (
         ReadStorage<'s, MobComponent>,
+        ReadStorage<'s, TargetComponent>,
         WriteStorage<'s, Motion2DComponent>,
         WriteStorage<'s, Transform>,
         WriteStorage<'s, Hitbox2DComponent>,
+        Read<'s, Time>,
     );
 
     /// System game logic
-    fn run(&mut self, (mobs, mut motion_2ds, mut transforms, mut hitbox_2ds): Self::SystemData) {
-        for (mob, motion_2d, hitbox_2d, transform) in
-            (&mobs, &mut motion_2ds, &mut hitbox_2ds, &mut transforms).join()
+    fn run(
+        &mut self,
+        (mobs, targets, mut motion_2ds, mut transforms, mut hitbox_2ds, time): Self::SystemData,
+    ) {
+        for (mob, target, motion_2d, hitbox_2d, transform) in (
+            &mobs,
+            targets.maybe(),
+            &mut motion_2ds,
+            &mut hitbox_2ds,
+            &mut transforms,
+        )
+            .join()
         {
-            move_mob(&mob, transform, motion_2d, hitbox_2d);
+            // mobs with a target component home in on their target
+            if let Some(target) = target {
+                move_homing(
+                    transform,
+                    motion_2d,
+                    hitbox_2d,
+                    target.turn_rate,
+                    time.delta_seconds(),
+                );
+            } else {
+                move_mob(&mob, transform, motion_2d, hitbox_2d);
+            }
         }
     }
 }
 
-/// Handles mob targeting
-pub struct MobTargetSystem;
+/// Handles targeting of entities with target components
+pub struct TargetSystem;
 
-impl<'s> System<'s> for MobTargetSystem {
+impl<'s> System<'s> for TargetSystem {
     /// Data used by the system
     type SystemData = (
-        WriteStorage<'s, MobComponent>,
+        WriteStorage<'s, TargetComponent>,
         WriteStorage<'s, Motion2DComponent>,
+        ReadStorage<'s, MobComponent>,
         ReadStorage<'s, PlayerComponent>,
+        ReadStorage<'s, DecoyComponent>,
+        ReadStorage<'s, HealthComponent>,
         ReadStorage<'s, Transform>,
+        Read<'s, Time>,
     );
 
     /// System game logic
-    fn run(&mut self, (mut mobs, mut motion_2ds, players, transforms): Self::SystemData) {
-        for (mob, transform, motion_2d) in (&mut mobs, &transforms, &mut motion_2ds).join() {
-            if let MobType::Enemy(EnemyType::Missile) = mob.mob_type {
-                let mut closest_player_position: Option<Vector2<f32>> = None;
-
-                for (_player, player_transform) in (&players, &transforms).join() {
-                    if let Some(closest_position) = closest_player_position {
-                        if distance(
-                            player_transform.translation().x,
-                            transform.translation().x,
-                            player_transform.translation().y,
-                            transform.translation().y,
-                        ) < distance(
-                            closest_position.x,
-                            transform.translation().x,
-                            closest_position.y,
-                            transform.translation().y,
-                        ) {
-                            closest_player_position = Some(Vector2::new(
-                                player_transform.translation().x,
-                                player_transform.translation().y,
-                            ));
-                        }
-                    } else {
-                        closest_player_position = Some(Vector2::new(
-                            player_transform.translation().x,
-                            player_transform.translation().y,
-                        ));
-                    }
-                }
+    fn run(
+        &mut self,
+        (
+            mut targets,
+            mut motion_2ds,
+            mobs,
+            players,
+            decoys,
+            healths,
+            transforms,
+            time,
+        ): Self::SystemData,
+    ) {
+        let candidate = |transform: &Transform, health: &HealthComponent| TargetCandidate {
+            position: Vector2::new(transform.translation().x, transform.translation().y),
+            health_ratio: health.value / health.max_value,
+        };
+
+        // decoys are targeted in place of players while they exist
+        let decoy_candidates: Vec<TargetCandidate> = (&decoys, &transforms)
+            .join()
+            .map(|(_decoy, transform)| TargetCandidate {
+                position: Vector2::new(transform.translation().x, transform.translation().y),
+                health_ratio: 1.0,
+            })
+            .collect();
+
+        let player_candidates: Vec<TargetCandidate> = if decoy_candidates.is_empty() {
+            (&players, &healths, &transforms)
+                .join()
+                .map(|(_player, health, transform)| candidate(transform, health))
+                .collect()
+        } else {
+            decoy_candidates
+        };
+
+        let mut ally_candidates = vec![];
+        let mut enemy_candidates = vec![];
+        for (mob, health, transform) in (&mobs, &healths, &transforms).join() {
+            match mob.mob_type {
+                MobType::Ally(_) => ally_candidates.push(candidate(transform, health)),
+                MobType::Enemy(_) => enemy_candidates.push(candidate(transform, health)),
+                MobType::Neutral(_) => {}
+            }
+        }
 
-                motion_2d.target_position = closest_player_position;
+        for (target, transform, motion_2d) in (&mut targets, &transforms, &mut motion_2ds).join() {
+            if target.should_retarget(time.delta_seconds()) {
+                motion_2d.target_position = target.select_target(
+                    Vector2::new(transform.translation().x, transform.translation().y),
+                    &player_candidates,
+                    &ally_candidates,
+                    &enemy_candidates,
+                );
             }
         }
     }
 }
 
+/// Handle movement of entities homing in on a target
+fn move_homing(
+    transform: &Transform,
+    motion_2d: &mut Motion2DComponent,
+    hitbox_2d: &mut Hitbox2DComponent,
+    turn_rate: f32,
+    delta_time: f32,
+) {
+    if motion_2d.target_position.is_some() {
+        //turn towards target
+        motion_2d.turn_towards_target(
+            Vector2::new(transform.translation().x, transform.translation().y),
+            transform.euler_angles().2.to_degrees() + 180.0,
+            turn_rate,
+            delta_time,
+        );
+        hitbox_2d.set_offset_rotation(transform.euler_angles().2);
+
+        motion_2d.move_forward(transform.euler_angles().2);
+    } else {
+        motion_2d.move_down();
+        motion_2d.brake_horizontal();
+    }
+}
+
 /// Handle movement of mobs by MobType
 fn move_mob(
     mob: &MobComponent,
@@ -433,21 +573,6 @@ fn move_mob(
             motion_2d.move_down();
             motion_2d.brake_horizontal();
         }
-        MobType::Enemy(EnemyType::Missile) => {
-            if motion_2d.target_position.is_some() {
-                //turn towards target
-                motion_2d.turn_towards_target(
-                    Vector2::new(transform.translation().x, transform.translation().y),
-                    transform.euler_angles().2.to_degrees() + 180.0,
-                );
-                hitbox_2d.set_offset_rotation(transform.euler_angles().2);
-
-                motion_2d.move_forward(transform.euler_angles().2);
-            } else {
-                motion_2d.move_down();
-                motion_2d.brake_horizontal();
-            }
-        }
         MobType::Enemy(EnemyType::RepeaterBody) => {
             // move down to position and then accelerate backwards
             if transform.translation().y > ARENA_MIN_Y + ARENA_HEIGHT - 30.0 {
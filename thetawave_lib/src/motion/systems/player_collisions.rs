@@ -1,15 +1,23 @@
 use crate::{
     audio::Sounds,
-    components::{AbilityDirection, BarrelRollAbilityComponent, BarrierComponent, HealthComponent},
+    components::{
+        AbilityDirection, BarrelRollAbilityComponent, BarrierComponent, Faction, FactionComponent,
+        HealthComponent, PlayerComponent,
+    },
+    constants::PLAYER_BLAST_SPRITE_INDEX,
     entities::EffectType,
-    events::{ConsumableGetEvent, ItemGetEvent, PlayAudioEvent, PlayerCollisionEvent},
+    events::{
+        ConsumableGetEvent, ItemGetEvent, ItemRemoveEvent, PlayAudioEvent, PlayerCollisionEvent,
+    },
     motion::{
-        components::Motion2DComponent,
+        components::{Motion2DComponent, TargetComponent},
         systems::{barrier_collision, immovable_collision, standard_collision},
     },
     resources::{GameParametersResource, SpriteSheetsResource},
     spawnable::{
-        components::{BlastComponent, ConsumableComponent, ItemComponent, MobComponent},
+        components::{
+            BlastComponent, ConsumableComponent, EliteComponent, ItemComponent, MobComponent,
+        },
         resources::EffectsResource,
     },
     weapons::BlastType,
@@ -17,8 +25,10 @@ use crate::{
 use amethyst::{
     core::transform::Transform,
     ecs::*,
+    renderer::SpriteRender,
     shrev::{EventChannel, ReaderId},
 };
+use rand::{thread_rng, Rng};
 
 /// Handles collisions between players and mobs
 #[derive(Default)]
@@ -33,9 +43,12 @@ impl<'s> System<'s> for PlayerMobCollisionSystem {
         Read<'s, EventChannel<PlayerCollisionEvent>>,
         Read<'s, GameParametersResource>,
         ReadStorage<'s, MobComponent>,
+        WriteStorage<'s, EliteComponent>,
         WriteStorage<'s, Motion2DComponent>,
         WriteStorage<'s, HealthComponent>,
         ReadStorage<'s, BarrelRollAbilityComponent>,
+        ReadStorage<'s, PlayerComponent>,
+        Write<'s, EventChannel<ItemRemoveEvent>>,
     );
 
     /// Sets up event readers
@@ -55,9 +68,12 @@ impl<'s> System<'s> for PlayerMobCollisionSystem {
             collision_event_channel,
             game_parameters,
             mobs,
+            mut elites,
             mut motions,
             mut healths,
             barrel_roll_abilities,
+            players,
+            mut item_remove_event_channel,
         ): Self::SystemData,
     ) {
         for event in collision_event_channel.read(self.event_reader.as_mut().unwrap()) {
@@ -79,7 +95,28 @@ impl<'s> System<'s> for PlayerMobCollisionSystem {
                 };
 
                 if !collision_damage_immune {
-                    spaceship_health.take_damage(mob.collision_damage);
+                    let damage_dealt = spaceship_health.take_damage(mob.collision_damage);
+
+                    if let Some(elite) = elites.get_mut(event.colliding_entity) {
+                        // vampiric elites restore health from damage dealt to the player
+                        if let Some(mob_health) = healths.get_mut(event.colliding_entity) {
+                            mob_health.value += damage_dealt * elite.vampiric_multiplier();
+                        }
+
+                        // cursed elites steal a random item, only on hits that deal damage
+                        if let Some(player) = players.get(event.player_entity) {
+                            if damage_dealt > 0.0 && elite.can_steal() && !player.items.is_empty() {
+                                let item_type = player.items
+                                    [thread_rng().gen_range(0, player.items.len())]
+                                .clone();
+                                elite.stolen_items.push(item_type.clone());
+                                item_remove_event_channel.single_write(ItemRemoveEvent {
+                                    player_entity: event.player_entity,
+                                    item_type,
+                                });
+                            }
+                        }
+                    }
                 }
 
                 if let Some(collision_velocity) = event.collision_velocity {
@@ -118,6 +155,10 @@ impl<'s> System<'s> for PlayerBlastCollisionSystem {
         WriteStorage<'s, BlastComponent>,
         ReadStorage<'s, BarrelRollAbilityComponent>,
         ReadStorage<'s, Transform>,
+        WriteStorage<'s, Motion2DComponent>,
+        WriteStorage<'s, FactionComponent>,
+        WriteStorage<'s, SpriteRender>,
+        WriteStorage<'s, TargetComponent>,
         ReadExpect<'s, EffectsResource>,
         ReadExpect<'s, SpriteSheetsResource>,
         ReadExpect<'s, LazyUpdate>,
@@ -143,6 +184,10 @@ impl<'s> System<'s> for PlayerBlastCollisionSystem {
             mut blasts,
             barrel_roll_abilities,
             transforms,
+            mut motion2ds,
+            mut factions,
+            mut sprite_renders,
+            mut targets,
             effects_resource,
             sprite_resource,
             lazy_update,
@@ -154,21 +199,43 @@ impl<'s> System<'s> for PlayerBlastCollisionSystem {
                 let spaceship_health = healths.get_mut(event.player_entity).unwrap();
                 let blast_transform = transforms.get(event.colliding_entity).unwrap();
 
-                let player_hittable = if let Some(barrel_roll_ability) =
+                // rolling players dodge or reflect blasts depending on their barrel roll
+                let (player_hittable, player_reflects) = if let Some(barrel_roll_ability) =
                     barrel_roll_abilities.get(event.player_entity)
                 {
                     if let AbilityDirection::None = barrel_roll_ability.action_direction {
-                        true
+                        (true, false)
                     } else {
-                        false
+                        (
+                            !barrel_roll_ability.blast_immune
+                                && !barrel_roll_ability.reflect_blasts,
+                            barrel_roll_ability.reflect_blasts,
+                        )
                     }
                 } else {
-                    true
+                    (true, false)
                 };
 
-                // first check if the blast is allied with the player
-                // TODO blast should not hit if player is currently barrel rolling
-                if player_hittable {
+                if player_reflects {
+                    if let BlastType::Enemy = blast.blast_type {
+                        // send the blast back as an ally blast
+                        blast.blast_type = BlastType::Ally;
+                        blast.hit_entities.clear();
+
+                        if let Some(faction) = factions.get_mut(event.colliding_entity) {
+                            faction.faction = Faction::from_blast_type(&blast.blast_type);
+                        }
+                        if let Some(motion2d) = motion2ds.get_mut(event.colliding_entity) {
+                            motion2d.velocity = -motion2d.velocity;
+                        }
+                        if let Some(sprite_render) = sprite_renders.get_mut(event.colliding_entity)
+                        {
+                            sprite_render.sprite_number = PLAYER_BLAST_SPRITE_INDEX;
+                        }
+                        targets.remove(event.colliding_entity);
+                    }
+                } else if player_hittable {
+                    // first check if the blast is allied with the player
                     match blast.blast_type {
                         // using match here for ease of adding enemy blast effects (such as poison) in the future
                         BlastType::Enemy => {
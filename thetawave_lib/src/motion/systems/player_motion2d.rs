@@ -17,10 +17,10 @@ impl<'s> System<'s> for PlayerMotion2DSystem {
 
     /// System game logic
     fn run(&mut self, (players, mut motion_2d_components, input): Self::SystemData) {
-        let x_move = input.axis_value("player_x").unwrap() as f32;
-        let y_move = input.axis_value("player_y").unwrap() as f32;
+        for (player, motion_2d) in (&players, &mut motion_2d_components).join() {
+            let x_move = input.axis_value(&player.action("player_x")).unwrap() as f32;
+            let y_move = input.axis_value(&player.action("player_y")).unwrap() as f32;
 
-        for (_player, motion_2d) in (&players, &mut motion_2d_components).join() {
             handle_spaceship_movement(motion_2d, x_move, y_move);
         }
     }
@@ -10,6 +10,7 @@ use amethyst::{
     core::transform::Transform,
     ecs::prelude::{Entities, LazyUpdate, ReadExpect},
 };
+use rand::{thread_rng, Rng};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -21,22 +22,61 @@ pub type RollProbabilities = Vec<(Option<DropTableType>, f32)>;
 pub struct DropRolls {
     pub roll_count: u8,
     pub roll_probs: RollProbabilities,
+    /// Tables that always drop one entry in addition to the rolls
+    #[serde(default)]
+    pub guaranteed_drops: Vec<DropTableType>,
 }
 
 impl DropRolls {
-    fn choose_drop_table(roll_probs: &RollProbabilities) -> &Option<DropTableType> {
-        let probs = roll_probs.iter().map(|roll_prob| roll_prob.1).collect();
+    /// Choose a drop table, luck raises each weight to the power of 1 / (1 + luck)
+    /// which evens out the weights and makes rarer tables more likely
+    fn choose_drop_table(roll_probs: &RollProbabilities, luck: f32) -> &Option<DropTableType> {
+        let probs = roll_probs
+            .iter()
+            .map(|roll_prob| roll_prob.1.powf(1.0 / (1.0 + luck)))
+            .collect();
         &roll_probs[weighted_rng(probs)].0
     }
 
-    fn choose_drop(drop_probs: &DropProbabilities) -> &SpawnableType {
-        let probs = drop_probs.iter().map(|drop_prob| drop_prob.1).collect();
+    /// Number of rolls, each full point of luck adds a roll and the rest of the luck is the
+    /// chance of one more roll, mobs without rolls never get bonus rolls
+    fn luck_roll_count(&self, luck: f32) -> usize {
+        if self.roll_count == 0 {
+            return 0;
+        }
+
+        let bonus_chance_roll = if thread_rng().gen::<f32>() < luck.fract() {
+            1
+        } else {
+            0
+        };
+
+        self.roll_count as usize + luck.trunc() as usize + bonus_chance_roll
+    }
+
+    /// Choose a drop from the table, items are weighted by their rarity tier in the given phase
+    fn choose_drop<'a>(
+        drop_probs: &'a DropProbabilities,
+        items_resource: &ItemsResource,
+        phase_idx: usize,
+    ) -> &'a SpawnableType {
+        let probs = drop_probs
+            .iter()
+            .map(|drop_prob| match &drop_prob.0 {
+                SpawnableType::Item(item_type) => {
+                    drop_prob.1 * items_resource.rarity_weight(item_type, phase_idx)
+                }
+                _ => drop_prob.1,
+            })
+            .collect();
         &drop_probs[weighted_rng(probs)].0
     }
 
     pub fn spawn(
         &self,
         spawn_transform: Transform,
+        phase_idx: usize,
+        luck: f32,
         drop_tables_resource: &ReadExpect<DropTablesResource>,
         consumables_resource: &ReadExpect<ConsumablesResource>,
         mobs_resource: &ReadExpect<MobsResource>,
@@ -46,24 +86,32 @@ impl DropRolls {
         entities: &Entities,
         lazy_update: &ReadExpect<LazyUpdate>,
     ) {
-        for _ in 0..self.roll_count {
+        // luck below zero has no effect
+        let luck = luck.max(0.0);
+        let mut drops: Vec<&DropTableType> = self.guaranteed_drops.iter().collect();
+
+        for _ in 0..self.luck_roll_count(luck) {
             // pick a drop table
-            if let Some(drop_table) = Self::choose_drop_table(&self.roll_probs) {
-                // spawn a drop from the table
-                spawn_spawnable(
-                    Self::choose_drop(&drop_tables_resource[drop_table]),
-                    true,
-                    spawn_transform.clone(),
-                    consumables_resource,
-                    mobs_resource,
-                    items_resource,
-                    effects_resource,
-                    spritesheets_resource,
-                    entities,
-                    lazy_update,
-                )
+            if let Some(drop_table) = Self::choose_drop_table(&self.roll_probs, luck) {
+                drops.push(drop_table);
             }
         }
+
+        // spawn a drop from each table
+        for drop_table in drops {
+            spawn_spawnable(
+                Self::choose_drop(&drop_tables_resource[drop_table], items_resource, phase_idx),
+                true,
+                spawn_transform.clone(),
+                consumables_resource,
+                mobs_resource,
+                items_resource,
+                effects_resource,
+                spritesheets_resource,
+                entities,
+                lazy_update,
+            )
+        }
     }
 }
 
@@ -71,5 +119,6 @@ impl DropRolls {
 pub enum DropTableType {
     Standard,
     Boss,
+    BossItems,
     MoneyAsteroid,
 }
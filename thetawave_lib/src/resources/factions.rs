@@ -0,0 +1,21 @@
+use crate::components::Faction;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Used for storing damage dealt between factions
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+pub struct FactionsResource {
+    /// Attacking factions mapped to damage multipliers against defending factions
+    pub damage_matrix: HashMap<Faction, HashMap<Faction, f32>>,
+}
+
+impl FactionsResource {
+    /// Damage multiplier of attacks from `attacker` against `defender` (0.0 if not listed)
+    pub fn damage_multiplier(&self, attacker: &Faction, defender: &Faction) -> f32 {
+        self.damage_matrix
+            .get(attacker)
+            .and_then(|multipliers| multipliers.get(defender))
+            .copied()
+            .unwrap_or(0.0)
+    }
+}
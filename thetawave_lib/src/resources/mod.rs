@@ -1,4 +1,14 @@
-use crate::components::PlayerComponent;
+use crate::{
+    components::{
+        AttractorComponent, BarrelRollAbilityComponent, HealthComponent, PlayerComponent,
+        SpecialAbilityComponent,
+    },
+    entities::ItemType,
+    motion::components::{Hitbox2DComponent, Motion2DComponent},
+    weapons::components::{
+        BeamComponent, BlasterComponent, ManualFireComponent, SecondaryWeapon, SecondaryWeaponType,
+    },
+};
 use amethyst::{
     assets::Handle,
     renderer::{palette::Srgba, SpriteSheet},
@@ -8,25 +18,52 @@ use std::collections::HashMap;
 
 pub mod defense;
 pub mod drops;
+pub mod factions;
 pub mod game_parameters;
 pub mod phases;
+pub mod save_profile;
 pub mod store;
 
 pub use self::defense::DefenseResource;
 pub use self::drops::{
     DropProbabilities, DropRolls, DropTableType, DropTablesResource, RollProbabilities,
 };
+pub use self::factions::FactionsResource;
 pub use self::game_parameters::GameParametersResource;
 pub use self::phases::{
-    BossType, InvasionFormationPool, InvasionRandomPool, Phase, PhaseManagerResource, PhaseType,
+    BossType, InvasionFormationPool, InvasionRandomPool, Phase, PhaseManagerResource,
+    PhaseObjective, PhaseType,
 };
-pub use self::store::StoreResource;
+pub use self::save_profile::{SaveProfileResource, Unlock, UnlocksResource};
+pub use self::store::{RestockTrigger, StoreMode, StoreResource};
 
 pub type PlayersResource = HashMap<String, PlayerEntityData>;
+/// Secondary weapon types mapped to the weapon unlocked by items
+pub type SecondaryWeaponsResource = HashMap<SecondaryWeaponType, SecondaryWeapon>;
 
+/// Data for a playable character
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct PlayerEntityData {
+    /// Description shown on the character select screen
+    #[serde(default)]
+    pub description: String,
+    pub sprite_render_data: SpriteRenderData,
     pub player_component: PlayerComponent,
+    pub hitbox_component: Hitbox2DComponent,
+    pub motion2d_component: Motion2DComponent,
+    pub health_component: HealthComponent,
+    pub blaster_component: BlasterComponent,
+    pub manual_fire_component: ManualFireComponent,
+    pub barrel_roll_ability_component: BarrelRollAbilityComponent,
+    pub attractor_component: AttractorComponent,
+    #[serde(default)]
+    pub beam_component: Option<BeamComponent>,
+    /// Special ability activated with the ability binding
+    #[serde(default)]
+    pub special_ability_component: Option<SpecialAbilityComponent>,
+    /// Items applied to the character at the start of the game
+    #[serde(default)]
+    pub starting_items: Vec<ItemType>,
 }
 
 #[derive(Clone)]
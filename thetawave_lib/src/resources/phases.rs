@@ -1,7 +1,9 @@
+use crate::motion::components::TargetSelector;
 use serde::{Deserialize, Serialize};
 #[derive(Clone, Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
 pub enum InvasionRandomPool {
     Level1Easy,
+    Level1Escort,
 }
 
 #[derive(Clone, Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
@@ -24,12 +26,24 @@ pub enum BossType {
     None,
 }
 
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub enum PhaseObjective {
+    // number of escorted mobs that must reach the bottom of the arena
+    Escort(usize),
+}
+
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct Phase {
     pub phase_type: PhaseType,
     pub boss_type: BossType,
+    // length in ticks (ignored if the phase has an objective)
     pub length: usize,
     pub boss_spawned: bool,
+    #[serde(default)]
+    pub objective: Option<PhaseObjective>,
+    // target preferred by homing enemies during the phase
+    #[serde(default)]
+    pub enemy_target: Option<TargetSelector>,
 }
 
 #[derive(Clone, Serialize, Deserialize, Debug, Default)]
@@ -39,6 +53,8 @@ pub struct PhaseManagerResource {
     pub current_tick: usize,
     pub tick_timer: f32,
     pub tick_length: f32,
+    #[serde(default)]
+    pub escorts_completed: usize,
 }
 
 impl PhaseManagerResource {
@@ -70,12 +86,18 @@ impl PhaseManagerResource {
 
             // check if the phase is over
             if let Some(phase) = self.get_current_phase() {
-                if self.current_tick >= phase.length {
+                let phase_over = match phase.objective {
+                    Some(PhaseObjective::Escort(count)) => self.escorts_completed >= count,
+                    None => self.current_tick >= phase.length,
+                };
+
+                if phase_over {
                     if self.phase_idx == self.phase_map.len() - 1 {
                         // TODO: end level
                     }
                     self.phase_idx += 1;
                     self.current_tick = 0;
+                    self.escorts_completed = 0;
                 }
             }
         }
@@ -0,0 +1,145 @@
+use crate::{
+    constants::{SAVE_PROFILE_BACKUP_EXTENSION, SAVE_PROFILE_DIRECTORY, SAVE_PROFILE_FILE},
+    entities::{ItemType, SpawnableType},
+    resources::StoreResource,
+};
+use amethyst::{
+    config::Config,
+    log::{error, warn},
+};
+use serde::{Deserialize, Serialize};
+use std::{fs, path::PathBuf};
+
+/// Content that can be bought in the meta shop
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Unlock {
+    /// Playable character from players.ron
+    Character(String),
+    /// Item added to the store pool
+    Item(ItemType),
+}
+
+/// Unlocks sold in the meta shop, content that is not listed is always available
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct UnlocksResource {
+    /// Unlocks mapped to their price in currency
+    pub unlocks: Vec<(Unlock, usize)>,
+}
+
+impl UnlocksResource {
+    /// Returns true if the content is not locked or has been bought
+    pub fn is_available(&self, unlock: &Unlock, profile: &SaveProfileResource) -> bool {
+        !self.unlocks.iter().any(|(locked, _)| locked == unlock) || profile.is_unlocked(unlock)
+    }
+
+    /// Remove locked items from the store's stock probabilities
+    pub fn filter_stock(&self, store: &mut StoreResource, profile: &SaveProfileResource) {
+        store
+            .stock_probs
+            .retain(|(spawnable_type, _)| match spawnable_type {
+                SpawnableType::Item(item_type) => {
+                    self.is_available(&Unlock::Item(item_type.clone()), profile)
+                }
+                _ => true,
+            });
+    }
+}
+
+/// Progress kept between runs
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SaveProfileResource {
+    /// Currency earned over every run
+    #[serde(default)]
+    pub lifetime_currency: usize,
+    /// Currency left to spend in the meta shop
+    #[serde(default)]
+    pub currency: usize,
+    /// Unlocks bought in the meta shop
+    #[serde(default)]
+    pub unlocked: Vec<Unlock>,
+    /// Furthest phase reached, counted from 1
+    #[serde(default)]
+    pub best_phase: usize,
+}
+
+impl SaveProfileResource {
+    /// Location of the profile in the user's data directory
+    pub fn path() -> Option<PathBuf> {
+        dirs::data_dir().map(|data_dir| {
+            data_dir
+                .join(SAVE_PROFILE_DIRECTORY)
+                .join(SAVE_PROFILE_FILE)
+        })
+    }
+
+    /// Load the profile, a new profile is used if none has been saved
+    ///
+    /// An unreadable profile is moved to a backup next to it so the next save doesn't overwrite it
+    pub fn load_profile() -> Self {
+        match Self::path() {
+            Some(path) if path.exists() => Self::load(&path).unwrap_or_else(|err| {
+                let backup_path = path.with_extension(SAVE_PROFILE_BACKUP_EXTENSION);
+                match fs::rename(&path, &backup_path) {
+                    Ok(()) => warn!(
+                        "failed to load save profile {:?}, moved it to {:?}: {}",
+                        path, backup_path, err
+                    ),
+                    Err(rename_err) => error!(
+                        "failed to load save profile {:?}: {}, and failed to back it up: {}",
+                        path, err, rename_err
+                    ),
+                }
+                Self::default()
+            }),
+            _ => Self::default(),
+        }
+    }
+
+    /// Write the profile to the user's data directory
+    pub fn save(&self) {
+        if let Some(path) = Self::path() {
+            if let Some(directory) = path.parent() {
+                if let Err(err) = fs::create_dir_all(directory) {
+                    error!("failed to create save directory {:?}: {}", directory, err);
+                    return;
+                }
+            }
+
+            if let Err(err) = self.write(&path) {
+                error!("failed to save profile {:?}: {}", path, err);
+            }
+        }
+    }
+
+    /// Returns true if the unlock has been bought
+    pub fn is_unlocked(&self, unlock: &Unlock) -> bool {
+        self.unlocked.contains(unlock)
+    }
+
+    /// Add currency earned during a run
+    pub fn earn(&mut self, amount: usize) {
+        self.lifetime_currency += amount;
+        self.currency += amount;
+    }
+
+    /// Record the phase reached, returns true if it is a new best
+    pub fn reach_phase(&mut self, phase_idx: usize) -> bool {
+        if phase_idx + 1 > self.best_phase {
+            self.best_phase = phase_idx + 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Spend currency on an unlock, returns true if it was bought
+    pub fn buy(&mut self, unlock: &Unlock, price: usize) -> bool {
+        if self.is_unlocked(unlock) || self.currency < price {
+            return false;
+        }
+
+        self.currency -= price;
+        self.unlocked.push(unlock.clone());
+        true
+    }
+}
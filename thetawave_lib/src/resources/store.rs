@@ -1,34 +1,168 @@
 use crate::{
     components::PlayerComponent,
-    constants::{ARENA_MAX_Y, ITEM_SPAWN_Y_OFFSET},
-    entities::SpawnableType,
+    constants::{ARENA_MAX_Y, ITEM_SPAWN_Y_OFFSET, SOLD_ITEM_STOCK_PROBABILITY},
+    entities::{ItemType, SpawnableType},
     resources::SpriteSheetsResource,
     spawnable::resources::{ConsumablesResource, ItemsResource},
 };
 use amethyst::{
     core::transform::Transform,
-    ecs::prelude::{Entities, LazyUpdate, ReadExpect},
+    ecs::prelude::{Entities, Entity, LazyUpdate, ReadExpect},
 };
 use rand::{thread_rng, Rng};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 pub type StockProbabilities = Vec<(SpawnableType, f32)>;
 
+/// When players can use the store
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum StoreMode {
+    /// Open during every phase
+    Always,
+    /// Open only during rest phases
+    Rest,
+    /// Open in a shop between phases with the game paused, the shop opens when a rest phase starts
+    Shop,
+}
+
+impl Default for StoreMode {
+    fn default() -> Self {
+        StoreMode::Always
+    }
+}
+
+/// What causes the store to restock
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum RestockTrigger {
+    /// Restock every restock period
+    Timer,
+    /// Restock when a new phase starts
+    Phase,
+}
+
+impl Default for RestockTrigger {
+    fn default() -> Self {
+        RestockTrigger::Timer
+    }
+}
+
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct StoreResource {
     pub stock_probs: StockProbabilities,
     pub restock_timer: f32,
     pub restock_period: f32,
     pub inventory: Vec<Option<SpawnableType>>,
+    /// When players can use the store
+    #[serde(default)]
+    pub mode: StoreMode,
+    /// What causes the store to restock
+    #[serde(default)]
+    pub restock_trigger: RestockTrigger,
+    /// Index of the phase during the last update
+    #[serde(skip)]
+    pub current_phase: Option<usize>,
+    /// Whether the current phase is a rest phase
+    #[serde(skip)]
+    pub is_rest_phase: bool,
+    /// Set when the game should pause and open the shop
+    #[serde(skip)]
+    pub shop_requested: bool,
+    /// Whether the shop between phases is open
+    #[serde(skip)]
+    pub shop_open: bool,
+    /// Set when the store icons should be redrawn even if the inventory didn't change
+    #[serde(skip)]
+    pub icons_outdated: bool,
+    /// Number of slots stocked by the store
+    #[serde(default = "default_slot_count")]
+    pub slot_count: usize,
+    /// Largest number of slots the store can have with slots granted by items
+    #[serde(default = "default_slot_count")]
+    pub max_slot_count: usize,
+    /// Slots granted by items held by the players
+    #[serde(skip)]
+    pub bonus_slots: usize,
+    /// Price of the first reroll after each restock
+    #[serde(default)]
+    pub reroll_price: usize,
+    /// Increase of the reroll price with each reroll until the next restock
+    #[serde(default)]
+    pub reroll_price_increase: usize,
+    /// Number of rerolls since the last restock
+    #[serde(skip)]
+    pub reroll_count: usize,
+    /// Slots kept through the next restock
+    #[serde(default)]
+    pub locked_slots: Vec<bool>,
+    /// Items sell for their price divided by this
+    #[serde(default = "default_sell_price_divisor")]
+    pub sell_price_divisor: usize,
+    /// Index of the slot highlighted by each player, by player index
+    #[serde(skip)]
+    pub highlighted_slots: HashMap<usize, usize>,
+    /// Player whose highlighted slot and stats are shown in the store details
+    #[serde(skip)]
+    pub inspecting_player: Option<Entity>,
+}
+
+fn default_slot_count() -> usize {
+    3
+}
+
+fn default_sell_price_divisor() -> usize {
+    2
 }
 
 impl StoreResource {
-    fn choose_stock(&mut self) {
-        self.inventory = vec![None, None, None];
-        let mut choose_pool = self.stock_probs.clone();
+    /// Number of slots currently stocked by the store
+    pub fn current_slot_count(&self) -> usize {
+        (self.slot_count + self.bonus_slots).min(self.max_slot_count.max(self.slot_count))
+    }
+
+    /// Returns true if the slot is kept through the next restock
+    pub fn is_locked(&self, inventory_index: usize) -> bool {
+        self.locked_slots
+            .get(inventory_index)
+            .copied()
+            .unwrap_or(false)
+    }
+
+    fn choose_stock(&mut self, items_resource: &ItemsResource) {
+        let slot_count = self.current_slot_count();
+        self.locked_slots.resize(slot_count, false);
+
+        // locked slots keep their stock, other slots are emptied
+        let mut inventory = vec![None; slot_count];
+        for (i, slot) in inventory.iter_mut().enumerate() {
+            if self.is_locked(i) {
+                *slot = self.inventory.get(i).cloned().flatten();
+            }
+        }
+        self.inventory = inventory;
+
+        // items are weighted by their rarity tier in the current phase,
+        // locked stock is not chosen again for another slot
+        let phase_idx = self.current_phase.unwrap_or(0);
+        let mut choose_pool: StockProbabilities = self
+            .stock_probs
+            .iter()
+            .filter(|element| !self.inventory.contains(&Some(element.0.clone())))
+            .map(|(entity_type, value)| match entity_type {
+                SpawnableType::Item(item_type) => (
+                    entity_type.clone(),
+                    value * items_resource.rarity_weight(item_type, phase_idx),
+                ),
+                _ => (entity_type.clone(), *value),
+            })
+            .collect();
+
+        // choose an item for each unlocked slot
+        for i in 0..slot_count {
+            if self.is_locked(i) {
+                continue;
+            }
 
-        // choose 3 items
-        for i in 0..3 {
             let total_probs = choose_pool.iter().fold(0.0, |sum, item| sum + item.1);
 
             // choose an item
@@ -40,17 +174,7 @@ impl StoreResource {
                 if sum > pos {
                     //let item_to_add = &items_resource.item_entities[&item_type];
                     choose_pool.retain(|element| element != &(entity_type.clone(), value)); // remove chosen item from cloned choose pool
-                    self.inventory[i] = Some(entity_type.clone());
-
-                    let entity_index = self
-                        .stock_probs
-                        .iter()
-                        .position(|element| element == &(entity_type.clone(), value))
-                        .unwrap();
-
-                    if let SpawnableType::Item(_) = entity_type {
-                        self.stock_probs[entity_index].1 /= 2.0; // divide probability of item appearing in store by 2
-                    }
+                    self.inventory[i] = Some(entity_type);
 
                     break;
                 }
@@ -69,12 +193,12 @@ impl StoreResource {
         spritesheets_resource: &ReadExpect<SpriteSheetsResource>,
         lazy_update: &ReadExpect<LazyUpdate>,
     ) -> bool {
-        if let Some(entity_type) = &self.inventory[inventory_index] {
+        if let Some(Some(entity_type)) = self.inventory.get(inventory_index) {
             match entity_type {
                 SpawnableType::Item(item_type) => {
-                    let item_data = items_resource.item_entities[item_type].clone();
-                    if player.money >= item_data.item_component.price {
-                        player.money -= item_data.item_component.price;
+                    let price = items_resource.price(item_type);
+                    if player.money >= price {
+                        player.money -= price;
 
                         let mut spawn_transform = Transform::default();
                         spawn_transform.set_translation_xyz(
@@ -100,6 +224,7 @@ impl StoreResource {
                         }
 
                         self.inventory[inventory_index] = None; //change item slot data to None
+                        self.unlock(inventory_index);
                         return true;
                     }
                 }
@@ -126,6 +251,7 @@ impl StoreResource {
                         );
 
                         self.inventory[inventory_index] = None; //change item slot data to None
+                        self.unlock(inventory_index);
                         return true;
                     }
                 }
@@ -135,14 +261,164 @@ impl StoreResource {
         false
     }
 
-    pub fn restock_when_ready(&mut self, dt: f32) -> bool {
-        if self.restock_timer > 0.0 {
-            self.restock_timer -= dt;
-        } else {
-            self.restock_timer = self.restock_period;
-            self.choose_stock();
+    /// Price paid to players selling the item
+    pub fn sell_price(&self, item_type: &ItemType, items_resource: &ItemsResource) -> usize {
+        items_resource.price(item_type) / self.sell_price_divisor.max(1)
+    }
+
+    /// Sell the player's selected item back to the store for a fraction of its price
+    pub fn sell(
+        &mut self,
+        player: &mut PlayerComponent,
+        items_resource: &ReadExpect<ItemsResource>,
+    ) -> Option<ItemType> {
+        let item_type = player.item_to_sell()?.clone();
+
+        player.money += self.sell_price(&item_type, items_resource);
+
+        // sold items can appear in the store again
+        for stock_prob in self.stock_probs.iter_mut() {
+            if stock_prob.0 == SpawnableType::Item(item_type.clone()) {
+                stock_prob.1 = stock_prob.1.max(SOLD_ITEM_STOCK_PROBABILITY);
+            }
+        }
+
+        Some(item_type)
+    }
+
+    /// Price of the next reroll
+    pub fn current_reroll_price(&self) -> usize {
+        self.reroll_price + self.reroll_count * self.reroll_price_increase
+    }
+
+    /// Pay to restock the unlocked slots immediately, the price increases with each reroll
+    pub fn reroll(&mut self, player: &mut PlayerComponent, items_resource: &ItemsResource) -> bool {
+        let price = self.current_reroll_price();
+        if player.money >= price {
+            player.money -= price;
+            self.reroll_count += 1;
+            self.choose_stock(items_resource);
             return true;
         }
         false
     }
+
+    /// Lock or unlock the slot highlighted by the player, only slots in stock can be locked
+    pub fn toggle_lock(&mut self, player_index: usize) -> bool {
+        let slot = self.highlighted_slot(player_index);
+        if self.is_locked(slot) {
+            self.unlock(slot);
+            return true;
+        }
+        if let Some(Some(_)) = self.inventory.get(slot) {
+            if self.locked_slots.len() <= slot {
+                self.locked_slots.resize(slot + 1, false);
+            }
+            self.locked_slots[slot] = true;
+            return true;
+        }
+        false
+    }
+
+    fn unlock(&mut self, inventory_index: usize) {
+        if let Some(locked) = self.locked_slots.get_mut(inventory_index) {
+            *locked = false;
+        }
+    }
+
+    /// Add or remove slots granted by items, changes take effect immediately
+    pub fn change_bonus_slots(&mut self, amount: isize) {
+        self.bonus_slots = (self.bonus_slots as isize + amount).max(0) as usize;
+
+        let slot_count = self.current_slot_count();
+        self.inventory.resize(slot_count, None);
+        self.locked_slots.resize(slot_count, false);
+        for highlighted_slot in self.highlighted_slots.values_mut() {
+            if *highlighted_slot >= slot_count {
+                *highlighted_slot = 0;
+            }
+        }
+    }
+
+    /// Position of the slot between the top (0.0) and the bottom (1.0) of the store panel,
+    /// slots are spread evenly over the panel
+    pub fn slot_position(&self, inventory_index: usize) -> f32 {
+        let slot_count = self.inventory.len();
+        if slot_count > 1 {
+            inventory_index as f32 / (slot_count - 1) as f32
+        } else {
+            0.0
+        }
+    }
+
+    /// Highlight the next slot for the given player
+    pub fn inspect_next(&mut self, player_entity: Entity, player_index: usize) {
+        let highlighted_slot =
+            (self.highlighted_slot(player_index) + 1) % self.inventory.len().max(1);
+        self.highlighted_slots
+            .insert(player_index, highlighted_slot);
+        self.inspecting_player = Some(player_entity);
+    }
+
+    /// Index of the slot highlighted by the player with the given index
+    pub fn highlighted_slot(&self, player_index: usize) -> usize {
+        self.highlighted_slots
+            .get(&player_index)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Returns true if players can currently buy, sell, reroll and lock in the store
+    pub fn is_open(&self) -> bool {
+        match self.mode {
+            StoreMode::Always => true,
+            StoreMode::Rest => self.is_rest_phase,
+            StoreMode::Shop => self.shop_open,
+        }
+    }
+
+    /// Restock the store when the restock period ends or when a new phase starts,
+    /// depending on the restock trigger
+    pub fn update(
+        &mut self,
+        dt: f32,
+        phase_idx: usize,
+        is_rest_phase: bool,
+        items_resource: &ItemsResource,
+    ) {
+        let phase_changed = self.current_phase != Some(phase_idx);
+        self.current_phase = Some(phase_idx);
+        self.is_rest_phase = is_rest_phase;
+
+        match self.restock_trigger {
+            RestockTrigger::Timer => {
+                if self.restock_timer > 0.0 {
+                    self.restock_timer -= dt;
+                } else {
+                    self.restock_timer = self.restock_period;
+                    self.restock(items_resource);
+                }
+            }
+
+            RestockTrigger::Phase => {
+                if phase_changed {
+                    self.restock(items_resource);
+                }
+            }
+        }
+
+        if phase_changed && is_rest_phase && self.mode == StoreMode::Shop {
+            self.shop_requested = true;
+        }
+    }
+
+    fn restock(&mut self, items_resource: &ItemsResource) {
+        self.reroll_count = 0;
+        self.choose_stock(items_resource);
+
+        // locks only last through one restock
+        self.locked_slots
+            .iter_mut()
+            .for_each(|locked| *locked = false);
+    }
 }
@@ -7,6 +7,7 @@ pub use self::{
     despawn::{DespawnAtBorderComponent, DespawnTimeLimitComponent},
     spawn::{
         AutoConsumableSpawnerComponent, AutoEffectSpawnerComponent, AutoItemSpawnerComponent,
-        AutoMobSpawnerComponent, AutoSpawnerComponent,
+        AutoMobSpawnerComponent, AutoSpawnerComponent, HealthThresholdSpawn,
+        HealthThresholdSpawnerComponent, MobSpawn, TimedMobSpawnerComponent,
     },
 };
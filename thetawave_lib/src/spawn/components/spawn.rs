@@ -1,4 +1,5 @@
 use crate::{
+    components::HealthComponent,
     entities::{ConsumableType, EffectType, ItemType, MobType, SpawnableType},
     resources::SpriteSheetsResource,
     spawnable::resources::{ConsumablesResource, EffectsResource, ItemsResource, MobsResource},
@@ -11,6 +12,149 @@ use amethyst::{
 
 use serde::{Deserialize, Serialize};
 
+/// Used for spawning a mob relative to the position of another entity
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MobSpawn {
+    /// Type of mob entity to spawn
+    pub mob_type: MobType,
+    /// Offset position from entity's Transform translation
+    #[serde(default = "des_offset")]
+    pub offset: Vector2<f32>,
+    /// Optional initial velocity replacing the mob's default velocity
+    #[serde(default)]
+    pub velocity: Option<Vector2<f32>>,
+}
+
+/// Default to no offset
+fn des_offset() -> Vector2<f32> {
+    Vector2::new(0.0, 0.0)
+}
+
+impl MobSpawn {
+    /// Spawn the mob offset from the given transform
+    pub fn spawn(
+        &self,
+        spawn_transform: &Transform,
+        spritesheets_resource: &ReadExpect<SpriteSheetsResource>,
+        mobs_resource: &ReadExpect<MobsResource>,
+        entities: &Entities,
+        lazy_update: &ReadExpect<LazyUpdate>,
+    ) {
+        // add offset position to translation position of entity
+        let mut adjusted_transform = spawn_transform.clone();
+        adjusted_transform.prepend_translation_x(self.offset.x);
+        adjusted_transform.prepend_translation_y(self.offset.y);
+
+        mobs_resource.spawn_mob_with_velocity(
+            &self.mob_type,
+            self.velocity,
+            adjusted_transform,
+            spritesheets_resource,
+            entities,
+            lazy_update,
+        );
+    }
+}
+
+/// Used for spawning mobs once after a delay
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TimedMobSpawnerComponent {
+    /// Mobs to spawn when the timer runs out
+    pub spawns: Vec<MobSpawn>,
+    /// Stores current time until spawn
+    timer: f32,
+}
+
+impl Component for TimedMobSpawnerComponent {
+    type Storage = DenseVecStorage<Self>;
+}
+
+impl TimedMobSpawnerComponent {
+    /// Spawn `spawns` once the timer runs out (call every frame)
+    pub fn spawn_when_ready(
+        &mut self,
+        delta_time: f32,
+        spawn_transform: &Transform,
+        spritesheets_resource: &ReadExpect<SpriteSheetsResource>,
+        mobs_resource: &ReadExpect<MobsResource>,
+        entities: &Entities,
+        lazy_update: &ReadExpect<LazyUpdate>,
+    ) {
+        // timer stays below 0.0 after spawning
+        if self.timer < 0.0 {
+            return;
+        }
+
+        self.timer -= delta_time;
+
+        if self.timer < 0.0 {
+            for mob_spawn in self.spawns.iter() {
+                mob_spawn.spawn(
+                    spawn_transform,
+                    spritesheets_resource,
+                    mobs_resource,
+                    entities,
+                    lazy_update,
+                );
+            }
+        }
+    }
+}
+
+/// Mobs spawned when health drops below a proportion of max health
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct HealthThresholdSpawn {
+    /// Proportion of max health at or below which the mobs are spawned
+    pub health_ratio: f32,
+    /// Mobs to spawn when the threshold is crossed
+    pub spawns: Vec<MobSpawn>,
+    /// Whether the threshold has already been crossed
+    #[serde(default)]
+    triggered: bool,
+}
+
+/// Used for spawning mobs when health thresholds are crossed
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct HealthThresholdSpawnerComponent {
+    /// Health thresholds with mobs to spawn
+    pub thresholds: Vec<HealthThresholdSpawn>,
+}
+
+impl Component for HealthThresholdSpawnerComponent {
+    type Storage = DenseVecStorage<Self>;
+}
+
+impl HealthThresholdSpawnerComponent {
+    /// Spawn mobs of each threshold crossed for the first time (call every frame)
+    pub fn spawn_when_crossed(
+        &mut self,
+        health_component: &HealthComponent,
+        spawn_transform: &Transform,
+        spritesheets_resource: &ReadExpect<SpriteSheetsResource>,
+        mobs_resource: &ReadExpect<MobsResource>,
+        entities: &Entities,
+        lazy_update: &ReadExpect<LazyUpdate>,
+    ) {
+        let health_ratio = health_component.value / health_component.max_value;
+
+        for threshold in self.thresholds.iter_mut() {
+            if !threshold.triggered && health_ratio <= threshold.health_ratio {
+                threshold.triggered = true;
+
+                for mob_spawn in threshold.spawns.iter() {
+                    mob_spawn.spawn(
+                        spawn_transform,
+                        spritesheets_resource,
+                        mobs_resource,
+                        entities,
+                        lazy_update,
+                    );
+                }
+            }
+        }
+    }
+}
+
 /// Used for periodically spawning spawnable entities
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct AutoSpawnerComponent {
@@ -0,0 +1,55 @@
+use crate::{
+    spawnable::components::{EliteAffix, EliteComponent},
+    tools::weighted_rng,
+};
+use amethyst::renderer::palette::Srgba;
+use rand::{thread_rng, Rng};
+use serde::{Deserialize, Serialize};
+
+/// Used for rolling elite affixes onto spawned mobs
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ElitePool {
+    /// Chance of a mob being elite in the first phase
+    pub base_chance: f32,
+    /// Chance added for every phase after the first
+    pub chance_per_phase: f32,
+    /// Maximum chance of a mob being elite
+    pub max_chance: f32,
+    /// Number of affixes rolled onto each elite mob
+    pub affix_count: usize,
+    /// Drop rolls added to elite mobs
+    pub bonus_drop_rolls: u8,
+    /// Tint applied to elite mobs
+    pub tint: Srgba,
+    /// Affixes mapped to random weights (higher number = more likely to be rolled)
+    pub affix_probs: Vec<(EliteAffix, f32)>,
+}
+
+impl ElitePool {
+    /// Chance of a mob being elite in the given phase
+    pub fn elite_chance(&self, phase_idx: usize) -> f32 {
+        (self.base_chance + self.chance_per_phase * phase_idx as f32).min(self.max_chance)
+    }
+
+    /// Roll for an elite component, returns None if the mob is not elite
+    pub fn roll(&self, phase_idx: usize) -> Option<EliteComponent> {
+        if thread_rng().gen::<f32>() >= self.elite_chance(phase_idx) {
+            return None;
+        }
+
+        // choose affixes without repeats
+        let mut choose_pool = self.affix_probs.clone();
+        let mut affixes = vec![];
+        while affixes.len() < self.affix_count && !choose_pool.is_empty() {
+            let probs = choose_pool.iter().map(|affix_prob| affix_prob.1).collect();
+            affixes.push(choose_pool.remove(weighted_rng(probs)).0);
+        }
+
+        Some(EliteComponent {
+            affixes,
+            tint: self.tint,
+            bonus_drop_rolls: self.bonus_drop_rolls,
+            stolen_items: vec![],
+        })
+    }
+}
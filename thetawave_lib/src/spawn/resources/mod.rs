@@ -1,5 +1,9 @@
 //! Resources for spawning and despawning entities
 
+mod elite;
 mod spawner;
 
-pub use self::spawner::{Formation, FormationSpawnable, RandomSpawnable, SpawnerResource};
+pub use self::{
+    elite::ElitePool,
+    spawner::{Formation, FormationSpawnable, RandomSpawnable, SpawnerResource},
+};
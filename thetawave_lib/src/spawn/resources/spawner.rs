@@ -1,7 +1,8 @@
 use crate::{
     constants::{ARENA_MAX_X, ARENA_MAX_Y, ARENA_MIN_X, ARENA_SPAWN_OFFSET, SPAWNER_Y_OFFSET},
-    entities::SpawnableType,
+    entities::{MobType, SpawnableType},
     resources::{InvasionFormationPool, InvasionRandomPool, SpriteSheetsResource},
+    spawn::resources::ElitePool,
     spawnable::resources::{
         spawn_spawnable, ConsumablesResource, EffectsResource, ItemsResource, MobsResource,
     },
@@ -39,6 +40,8 @@ impl Formation {
     /// Spawn all entities in formation at their positions
     pub fn spawn_formation(
         &self,
+        elite_pool: &ElitePool,
+        phase_idx: usize,
         consumables_resource: &ReadExpect<ConsumablesResource>,
         mobs_resource: &ReadExpect<MobsResource>,
         items_resource: &ReadExpect<ItemsResource>,
@@ -55,10 +58,11 @@ impl Formation {
                 0.0,
             );
 
-            spawn_spawnable(
+            spawn_with_elite_roll(
                 &formation_spawnable.spawnable_type,
-                false,
                 spawn_transform,
+                elite_pool,
+                phase_idx,
                 consumables_resource,
                 mobs_resource,
                 items_resource,
@@ -89,6 +93,8 @@ pub struct SpawnerResource {
     pub random_pools: HashMap<InvasionRandomPool, Vec<RandomSpawnable>>,
     /// Pools of formations to be spawned randomly
     pub formation_pools: HashMap<InvasionFormationPool, Vec<Formation>>,
+    /// Pool of affixes rolled onto spawned enemy mobs
+    pub elite_pool: ElitePool,
     /// Counts down time between spawns
     pub timer: f32,
 }
@@ -124,6 +130,7 @@ impl SpawnerResource {
     pub fn spawn_random_spawnable_when_ready(
         &mut self,
         random_pool_type: &InvasionRandomPool,
+        phase_idx: usize,
         dt: f32,
         consumables_resource: &ReadExpect<ConsumablesResource>,
         mobs_resource: &ReadExpect<MobsResource>,
@@ -145,10 +152,11 @@ impl SpawnerResource {
             );
 
             if let Some(spawnable_type) = &random_spawnable.spawnable_type {
-                spawn_spawnable(
+                spawn_with_elite_roll(
                     spawnable_type,
-                    false,
                     spawn_transform,
+                    &self.elite_pool,
+                    phase_idx,
                     consumables_resource,
                     mobs_resource,
                     items_resource,
@@ -186,6 +194,7 @@ impl SpawnerResource {
     pub fn spawn_random_formation_when_ready(
         &mut self,
         formation_pool_type: &InvasionFormationPool,
+        phase_idx: usize,
         dt: f32,
         consumables_resource: &ReadExpect<ConsumablesResource>,
         mobs_resource: &ReadExpect<MobsResource>,
@@ -200,6 +209,8 @@ impl SpawnerResource {
         if self.timer <= 0.0 {
             let formation = self.choose_random_formation(formation_pool_type);
             formation.spawn_formation(
+                &self.elite_pool,
+                phase_idx,
                 consumables_resource,
                 mobs_resource,
                 items_resource,
@@ -213,3 +224,45 @@ impl SpawnerResource {
         }
     }
 }
+
+/// Spawn a spawnable entity, rolling for elite affixes if it is an enemy mob
+fn spawn_with_elite_roll(
+    spawnable_type: &SpawnableType,
+    spawn_transform: Transform,
+    elite_pool: &ElitePool,
+    phase_idx: usize,
+    consumables_resource: &ReadExpect<ConsumablesResource>,
+    mobs_resource: &ReadExpect<MobsResource>,
+    items_resource: &ReadExpect<ItemsResource>,
+    effects_resource: &ReadExpect<EffectsResource>,
+    spritesheets_resource: &ReadExpect<SpriteSheetsResource>,
+    entities: &Entities,
+    lazy_update: &ReadExpect<LazyUpdate>,
+) {
+    if let SpawnableType::Mob(mob_type @ MobType::Enemy(_)) = spawnable_type {
+        if let Some(elite_component) = elite_pool.roll(phase_idx) {
+            mobs_resource.spawn_elite_mob(
+                mob_type,
+                elite_component,
+                spawn_transform,
+                spritesheets_resource,
+                entities,
+                lazy_update,
+            );
+            return;
+        }
+    }
+
+    spawn_spawnable(
+        spawnable_type,
+        false,
+        spawn_transform,
+        consumables_resource,
+        mobs_resource,
+        items_resource,
+        effects_resource,
+        spritesheets_resource,
+        entities,
+        lazy_update,
+    );
+}
@@ -42,7 +42,7 @@ impl<'s> System<'s> for DespawnAtBorderSystem {
                 if transform.translation().y < ARENA_MIN_Y + bottom_border_offset {
                     if let Some(mob) = mobs.get(entity) {
                         mob_reached_bottom_event_channel
-                            .single_write(MobReachedBottomEvent::new(mob.defense_damage));
+                            .single_write(MobReachedBottomEvent::new(entity, mob.defense_damage));
                     }
                     entities.delete(entity).expect("unable to delete entity");
                 }
@@ -5,5 +5,7 @@ mod spawner;
 
 pub use self::{
     despawn::{DespawnAtBorderSystem, DespawnTimeLimitSystem},
-    spawner::{AutoSpawnerSystem, SpawnerSystem},
+    spawner::{
+        AutoSpawnerSystem, HealthThresholdSpawnerSystem, SpawnerSystem, TimedMobSpawnerSystem,
+    },
 };
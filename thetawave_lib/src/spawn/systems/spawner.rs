@@ -1,7 +1,13 @@
 use crate::{
+    components::HealthComponent,
     entities::spawn_repeater,
     resources::{BossType, PhaseManagerResource, PhaseType, SpriteSheetsResource},
-    spawn::{components::AutoSpawnerComponent, resources::SpawnerResource},
+    spawn::{
+        components::{
+            AutoSpawnerComponent, HealthThresholdSpawnerComponent, TimedMobSpawnerComponent,
+        },
+        resources::SpawnerResource,
+    },
     spawnable::resources::{ConsumablesResource, EffectsResource, ItemsResource, MobsResource},
 };
 use amethyst::{
@@ -46,10 +52,13 @@ impl<'s> System<'s> for SpawnerSystem {
             mobs_resource,
         ): Self::SystemData,
     ) {
+        let phase_idx = phase_manager.phase_idx;
+
         match phase_manager.get_current_phase_type() {
             Some(PhaseType::InvasionRandom(random_pool_type)) => spawner_resource
                 .spawn_random_spawnable_when_ready(
                     &random_pool_type,
+                    phase_idx,
                     time.delta_seconds(),
                     &consumables_resource,
                     &mobs_resource,
@@ -63,6 +72,7 @@ impl<'s> System<'s> for SpawnerSystem {
             Some(PhaseType::InvasionFormation(formation_pool_type)) => spawner_resource
                 .spawn_random_formation_when_ready(
                     &&formation_pool_type,
+                    phase_idx,
                     time.delta_seconds(),
                     &consumables_resource,
                     &mobs_resource,
@@ -84,7 +94,6 @@ impl<'s> System<'s> for SpawnerSystem {
                                 &entities,
                                 &lazy_update,
                             );
-                            let phase_idx = phase_manager.phase_idx;
                             phase_manager.phase_map[phase_idx].boss_spawned = true;
                         }
                     }
@@ -151,3 +160,87 @@ impl<'s> System<'s> for AutoSpawnerSystem {
         }
     }
 }
+
+/// Handles spawning of mobs using timed spawner components
+pub struct TimedMobSpawnerSystem;
+
+impl<'s> System<'s> for TimedMobSpawnerSystem {
+    /// Data used by the system
+    type SystemData = (
+        ReadStorage<'s, Transform>,
+        WriteStorage<'s, TimedMobSpawnerComponent>,
+        Read<'s, Time>,
+        ReadExpect<'s, LazyUpdate>,
+        ReadExpect<'s, MobsResource>,
+        ReadExpect<'s, SpriteSheetsResource>,
+        Entities<'s>,
+    );
+
+    /// System game logic
+    fn run(
+        &mut self,
+        (
+            transforms,
+            mut timed_spawners,
+            time,
+            lazy_update,
+            mobs_resource,
+            sprite_sheets_resource,
+            entities,
+        ): Self::SystemData,
+    ) {
+        for (transform, timed_spawner) in (&transforms, &mut timed_spawners).join() {
+            timed_spawner.spawn_when_ready(
+                time.delta_seconds(),
+                transform,
+                &sprite_sheets_resource,
+                &mobs_resource,
+                &entities,
+                &lazy_update,
+            );
+        }
+    }
+}
+
+/// Handles spawning of mobs using health threshold spawner components
+pub struct HealthThresholdSpawnerSystem;
+
+impl<'s> System<'s> for HealthThresholdSpawnerSystem {
+    /// Data used by the system
+    type SystemData = (
+        ReadStorage<'s, Transform>,
+        ReadStorage<'s, HealthComponent>,
+        WriteStorage<'s, HealthThresholdSpawnerComponent>,
+        ReadExpect<'s, LazyUpdate>,
+        ReadExpect<'s, MobsResource>,
+        ReadExpect<'s, SpriteSheetsResource>,
+        Entities<'s>,
+    );
+
+    /// System game logic
+    fn run(
+        &mut self,
+        (
+            transforms,
+            healths,
+            mut health_threshold_spawners,
+            lazy_update,
+            mobs_resource,
+            sprite_sheets_resource,
+            entities,
+        ): Self::SystemData,
+    ) {
+        for (transform, health, health_threshold_spawner) in
+            (&transforms, &healths, &mut health_threshold_spawners).join()
+        {
+            health_threshold_spawner.spawn_when_crossed(
+                health,
+                transform,
+                &sprite_sheets_resource,
+                &mobs_resource,
+                &entities,
+                &lazy_update,
+            );
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use crate::weapons::BlastType;
-use amethyst::ecs::prelude::{Component, DenseVecStorage};
+use amethyst::ecs::prelude::{Component, DenseVecStorage, Entity};
 
 /// Used for data unique to blast entities
 #[derive(Clone)]
@@ -10,6 +10,14 @@ pub struct BlastComponent {
     pub poison_damage: f32,
     /// Type of blast
     pub blast_type: BlastType,
+    /// Number of mobs the blast passes through before it is destroyed
+    pub piercing: usize,
+    /// Number of times the blast bounces off arena borders
+    pub bounces: usize,
+    /// Radius in which the blast damages other mobs when it hits (no explosion if 0.0)
+    pub explosion_radius: f32,
+    /// Mobs already hit by the blast
+    pub hit_entities: Vec<Entity>,
 }
 
 impl Component for BlastComponent {
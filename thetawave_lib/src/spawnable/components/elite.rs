@@ -0,0 +1,102 @@
+use crate::{
+    components::HealthComponent, entities::ItemType, motion::components::Motion2DComponent,
+    spawnable::components::MobComponent,
+};
+use amethyst::{
+    ecs::prelude::{Component, DenseVecStorage},
+    renderer::palette::Srgba,
+};
+use serde::{Deserialize, Serialize};
+
+/// Affixes that can be rolled onto elite mobs
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub enum EliteAffix {
+    /// Armor added to the mob's health component
+    Armored(usize),
+    /// Multiplier applied to the mob's acceleration and speeds
+    Swift(f32),
+    /// Number of regular copies of the mob spawned on death
+    Splitting(usize),
+    /// Shields added to the mob's health component
+    Shielded(f32),
+    /// Proportion of collision damage dealt to players restored as health
+    Vampiric(f32),
+    /// Number of items the mob can steal from players it collides with
+    Cursed(usize),
+}
+
+/// Used for data unique to elite mobs
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct EliteComponent {
+    /// Affixes rolled onto the mob
+    pub affixes: Vec<EliteAffix>,
+    /// Tint applied to the mob's sprite
+    pub tint: Srgba,
+    /// Drop rolls added to the mob's drop rolls
+    pub bonus_drop_rolls: u8,
+    /// Items stolen from players, dropped when the mob is destroyed
+    #[serde(default)]
+    pub stolen_items: Vec<ItemType>,
+}
+
+impl Component for EliteComponent {
+    type Storage = DenseVecStorage<Self>;
+}
+
+impl EliteComponent {
+    /// Apply affixes to the components of a mob before it is spawned
+    pub fn apply(
+        &self,
+        mob_component: &mut MobComponent,
+        health_component: &mut HealthComponent,
+        motion2d_component: &mut Motion2DComponent,
+    ) {
+        mob_component.drop_rolls.roll_count += self.bonus_drop_rolls;
+
+        for affix in self.affixes.iter() {
+            match affix {
+                EliteAffix::Armored(val) => {
+                    health_component.armor += val;
+                }
+
+                EliteAffix::Swift(val) => {
+                    motion2d_component.acceleration *= *val;
+                    motion2d_component.speed *= *val;
+                    motion2d_component.max_speed *= *val;
+                }
+
+                EliteAffix::Shielded(val) => {
+                    health_component.shields += val;
+                }
+
+                EliteAffix::Splitting(_) | EliteAffix::Vampiric(_) | EliteAffix::Cursed(_) => {}
+            }
+        }
+    }
+
+    /// Number of copies of the mob to spawn on death
+    pub fn splitting_count(&self) -> usize {
+        self.affixes.iter().fold(0, |count, affix| match affix {
+            EliteAffix::Splitting(val) => count + val,
+            _ => count,
+        })
+    }
+
+    /// Returns true if the mob can still steal an item
+    pub fn can_steal(&self) -> bool {
+        let steal_count = self.affixes.iter().fold(0, |count, affix| match affix {
+            EliteAffix::Cursed(val) => count + val,
+            _ => count,
+        });
+
+        self.stolen_items.len() < steal_count
+    }
+
+    /// Proportion of collision damage restored as health
+    pub fn vampiric_multiplier(&self) -> f32 {
+        self.affixes.iter().fold(0.0, |multiplier, affix| match affix {
+            EliteAffix::Vampiric(val) => multiplier + val,
+            _ => multiplier,
+        })
+    }
+}
@@ -0,0 +1,15 @@
+use amethyst::ecs::prelude::{Component, DenseVecStorage};
+use serde::{Deserialize, Serialize};
+
+/// Used for data unique to mobs escorted by the player
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct EscortComponent {
+    /// Money paid to each player when the mob reaches the bottom of the arena
+    pub reward_money: usize,
+    /// Damage to defense when the mob is destroyed
+    pub destroyed_defense_damage: f32,
+}
+
+impl Component for EscortComponent {
+    type Storage = DenseVecStorage<Self>;
+}
@@ -2,9 +2,16 @@
 
 mod blast;
 mod consumable;
+mod elite;
+mod escort;
 mod item;
 mod mob;
 
 pub use self::{
-    blast::BlastComponent, consumable::ConsumableComponent, item::ItemComponent, mob::MobComponent,
+    blast::BlastComponent,
+    consumable::ConsumableComponent,
+    elite::{EliteAffix, EliteComponent},
+    escort::EscortComponent,
+    item::ItemComponent,
+    mob::MobComponent,
 };
@@ -1,13 +1,14 @@
 //! Resources for spawnable entities
 use crate::{
+    components::{Faction, FactionComponent},
     entities::SpawnableType,
-    motion::components::{Hitbox2DComponent, Motion2DComponent},
+    motion::components::{Hitbox2DComponent, Motion2DComponent, TargetComponent},
     resources::SpriteSheetsResource,
-    spawn::components::DespawnAtBorderComponent,
+    spawn::components::{DespawnAtBorderComponent, DespawnTimeLimitComponent},
     spawnable::components::BlastComponent,
 };
 use amethyst::{
-    core::transform::Transform,
+    core::{math::Vector2, transform::Transform},
     ecs::prelude::{Builder, Entities, LazyUpdate, ReadExpect},
     renderer::{SpriteRender, Transparent},
 };
@@ -16,11 +17,15 @@ mod modifiers;
 mod spawnables;
 
 pub use self::{
-    modifiers::{ConsumableModifiersResource, ItemModifiersResource, Modifier},
+    modifiers::{
+        BuffData, BuffStacking, BuffType, BuffsResource, ConsumableModifiersResource,
+        ItemModifiersResource, Modifier, StatChange, StatClamp, StatClampsResource, StatType,
+        SynergiesResource, SynergyData, SynergyType,
+    },
     spawnables::{
         ConsumableEntityData, ConsumablesResource, EffectEntityData, EffectsResource,
-        ItemEntityData, ItemsResource, MobEntityData, MobsResource, RandomMotionRange2D,
-        ThrusterEntityData,
+        ItemEntityData, ItemRarity, ItemsResource, MobEntityData, MobsResource,
+        RandomMotionRange2D, RarityData, ThrusterEntityData,
     },
 };
 
@@ -86,20 +91,37 @@ pub fn spawn_spawnable(
 pub fn spawn_blasts(
     blast_count: usize,
     blast_spacing: f32,
+    blast_spread_angle: f32,
     blast_sprite_render: SpriteRender,
     blast_component: BlastComponent,
     blast_hitbox: Hitbox2DComponent,
     blast_motion2d: Motion2DComponent,
+    blast_target: Option<TargetComponent>,
+    blast_lifetime: Option<f32>,
     mut blast_transform: Transform,
     entities: &Entities,
     lazy_update: &ReadExpect<LazyUpdate>,
 ) {
-    for _ in 0..blast_count {
-        lazy_update
+    for blast_index in 0..blast_count {
+        // blasts fan out from the center of the spread, left blasts turn left
+        let blast_angle = -blast_spread_angle.to_radians()
+            * (blast_index as f32 - (blast_count - 1) as f32 / 2.0);
+        let mut blast_motion2d = blast_motion2d.clone();
+        blast_motion2d.velocity = Vector2::new(
+            blast_motion2d.velocity.x * blast_angle.cos()
+                - blast_motion2d.velocity.y * blast_angle.sin(),
+            blast_motion2d.velocity.x * blast_angle.sin()
+                + blast_motion2d.velocity.y * blast_angle.cos(),
+        );
+
+        let blast_entity = lazy_update
             .create_entity(entities)
+            .with(FactionComponent {
+                faction: Faction::from_blast_type(&blast_component.blast_type),
+            })
             .with(blast_component.clone())
             .with(blast_hitbox.clone())
-            .with(blast_motion2d.clone())
+            .with(blast_motion2d)
             .with(blast_sprite_render.clone())
             .with(blast_transform.clone())
             .with(Transparent)
@@ -111,6 +133,14 @@ pub fn spawn_blasts(
             })
             .build();
 
+        if let Some(blast_target) = blast_target.clone() {
+            lazy_update.insert(blast_entity, blast_target);
+        }
+
+        if let Some(duration) = blast_lifetime {
+            lazy_update.insert(blast_entity, DespawnTimeLimitComponent { duration });
+        }
+
         blast_transform.prepend_translation_x(blast_spacing);
     }
 }
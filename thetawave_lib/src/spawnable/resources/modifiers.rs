@@ -1,4 +1,8 @@
-use crate::entities::{ConsumableType, ItemType};
+use crate::{
+    entities::{ConsumableType, ItemType},
+    resources::SpriteRenderData,
+    weapons::components::SecondaryWeaponType,
+};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -6,12 +10,160 @@ use std::collections::HashMap;
 pub type ItemModifiersResource = HashMap<ItemType, Vec<Modifier>>;
 /// Consumable types mapped to a collection of modifiers
 pub type ConsumableModifiersResource = HashMap<ConsumableType, Vec<Modifier>>;
+/// Stat types mapped to the bounds of their effective values
+pub type StatClampsResource = HashMap<StatType, StatClamp>;
+/// Buff types mapped to their duration and modifiers
+pub type BuffsResource = HashMap<BuffType, BuffData>;
+/// Synergy types mapped to their required items and modifiers
+pub type SynergiesResource = HashMap<SynergyType, SynergyData>;
+
+/// Combinations of items that unlock extra modifiers
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+pub enum SynergyType {
+    Scattershot,
+    ToxicFortune,
+    SingularityField,
+}
+
+/// Data of an item combination
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct SynergyData {
+    /// Name announced when the synergy is unlocked
+    pub name: String,
+    /// Items that must all be held to unlock the synergy
+    pub items: Vec<ItemType>,
+    /// Modifiers applied while the synergy is unlocked
+    pub modifiers: Vec<Modifier>,
+}
+
+/// Temporary effects granted by modifiers
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+pub enum BuffType {
+    Overclock,
+    Magnet,
+}
+
+/// How collecting a buff that is already active is handled
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq)]
+pub enum BuffStacking {
+    /// Reset the remaining time to the full duration
+    Refresh,
+    /// Add the full duration to the remaining time
+    Extend,
+    /// Apply another copy of the buff with its own timer
+    Stack,
+}
+
+/// Data of a temporary effect
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct BuffData {
+    /// Time in seconds until the buff expires
+    pub duration: f32,
+    /// Handling of collecting the buff while it is active
+    pub stacking: BuffStacking,
+    /// Modifiers applied while the buff is active
+    pub modifiers: Vec<Modifier>,
+    /// Icon shown on the side panel while the buff is active
+    pub sprite_render_data: SpriteRenderData,
+}
+
+/// Player stats computed from base values and modifiers
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+pub enum StatType {
+    ProjectileFirePeriod,
+    ChargeTime,
+    ProjectileDamage,
+    ProjectileCount,
+    ProjectileSize,
+    ProjectilePiercing,
+    ProjectileBounces,
+    ProjectileExplosionRadius,
+    ProjectileLifetime,
+    ProjectileSpread,
+    CriticalDamageChance,
+    PoisonChance,
+    MaximumSpeed,
+    Acceleration,
+    Deceleration,
+    AbilityCooldown,
+    MaximumHealth,
+    ConsumableAttractorRadius,
+    ConsumableAttractorAcceleration,
+    ItemAttractorRadius,
+    ItemAttractorAcceleration,
+    BlastAttractorRadius,
+    BlastAttractorAcceleration,
+    Luck,
+}
+
+impl StatType {
+    /// Short name displayed in the store
+    pub fn label(&self) -> &str {
+        match self {
+            StatType::ProjectileFirePeriod => "fire period",
+            StatType::ChargeTime => "charge time",
+            StatType::ProjectileDamage => "damage",
+            StatType::ProjectileCount => "blasts",
+            StatType::ProjectileSize => "blast size",
+            StatType::ProjectilePiercing => "piercing",
+            StatType::ProjectileBounces => "bounces",
+            StatType::ProjectileExplosionRadius => "explosion radius",
+            StatType::ProjectileLifetime => "blast lifetime",
+            StatType::ProjectileSpread => "spread",
+            StatType::CriticalDamageChance => "crit chance",
+            StatType::PoisonChance => "poison chance",
+            StatType::MaximumSpeed => "max speed",
+            StatType::Acceleration => "acceleration",
+            StatType::Deceleration => "deceleration",
+            StatType::AbilityCooldown => "roll cooldown",
+            StatType::MaximumHealth => "max health",
+            StatType::ConsumableAttractorRadius => "consumable pull",
+            StatType::ConsumableAttractorAcceleration => "consumable pull speed",
+            StatType::ItemAttractorRadius => "item pull",
+            StatType::ItemAttractorAcceleration => "item pull speed",
+            StatType::BlastAttractorRadius => "blast repel",
+            StatType::BlastAttractorAcceleration => "blast repel speed",
+            StatType::Luck => "luck",
+        }
+    }
+}
+
+/// Change to a stat made by a modifier
+#[derive(Clone, Copy, Debug)]
+pub enum StatChange {
+    /// Added to the base value
+    Add(f32),
+    /// Multiplies the base value after all additions
+    Multiply(f32),
+}
+
+/// Bounds of the effective value of a stat
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+pub struct StatClamp {
+    /// Minimum effective value
+    #[serde(default)]
+    pub min: Option<f32>,
+    /// Maximum effective value
+    #[serde(default)]
+    pub max: Option<f32>,
+}
+
+impl StatClamp {
+    /// Constrain a value to the bounds
+    pub fn apply(&self, value: f32) -> f32 {
+        let value = self.min.map_or(value, |min| value.max(min));
+        self.max.map_or(value, |max| value.min(max))
+    }
+}
 
 /// Effects stored in consumables and items that are applied to the player when collected
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub enum Modifier {
     BarrelImmunity(bool),
+    BarrelBlastImmunity(bool),
+    BarrelBlastReflect(bool),
     ProjectileFirePeriod(f32),
+    ChargeTime(f32),
     ProjectileDamage(f32),
     MaximumSpeed(f32),
     Deceleration(f32),
@@ -25,8 +177,19 @@ pub enum Modifier {
     MaximumHealth(f32),
     Health(f32),
     ProjectileSize(f32),
+    ProjectilePiercing(usize),
+    ProjectileBounces(usize),
+    ProjectileExplosionRadius(f32),
+    ProjectileLifetime(f32),
+    ProjectileSpread(f32),
+    SecondaryWeapon(SecondaryWeaponType),
+    SecondaryAmmo(usize),
+    SecondaryMaximumAmmo(usize),
     Armor(usize),
     Money(usize),
+    Bombs(usize),
+    StoreSlots(usize),
+    Luck(f32),
     ConsumableAttractorRadius(f32),
     ConsumableAttractorAcceleration(f32),
     ItemAttractorRadius(f32),
@@ -35,4 +198,60 @@ pub enum Modifier {
     BlastAttractorAcceleration(f32),
     BlastAttractorRadius(f32),
     BlastAttractorIsActive(bool),
+    StatMultiplier(StatType, f32),
+    Buff(BuffType),
+}
+
+impl Modifier {
+    /// Returns true if the modifier sets a flag of the player
+    pub fn is_flag(&self) -> bool {
+        matches!(
+            self,
+            Modifier::BarrelImmunity(_)
+                | Modifier::BarrelBlastImmunity(_)
+                | Modifier::BarrelBlastReflect(_)
+                | Modifier::BlastAttractorIsActive(_)
+                | Modifier::BlastAttractorShouldRepel(_)
+        )
+    }
+
+    /// Stat changed by the modifier, None if the modifier does not change a stat
+    pub fn stat_change(&self) -> Option<(StatType, StatChange)> {
+        let (stat_type, val) = match self {
+            Modifier::ProjectileFirePeriod(val) => (StatType::ProjectileFirePeriod, *val),
+            Modifier::ChargeTime(val) => (StatType::ChargeTime, *val),
+            Modifier::ProjectileDamage(val) => (StatType::ProjectileDamage, *val),
+            Modifier::ProjectileCount(val) => (StatType::ProjectileCount, *val as f32),
+            Modifier::ProjectileSize(val) => (StatType::ProjectileSize, *val),
+            Modifier::ProjectilePiercing(val) => (StatType::ProjectilePiercing, *val as f32),
+            Modifier::ProjectileBounces(val) => (StatType::ProjectileBounces, *val as f32),
+            Modifier::ProjectileExplosionRadius(val) => (StatType::ProjectileExplosionRadius, *val),
+            Modifier::ProjectileLifetime(val) => (StatType::ProjectileLifetime, *val),
+            Modifier::ProjectileSpread(val) => (StatType::ProjectileSpread, *val),
+            Modifier::Luck(val) => (StatType::Luck, *val),
+            Modifier::CriticalDamageChance(val) => (StatType::CriticalDamageChance, *val),
+            Modifier::PoisonChance(val) => (StatType::PoisonChance, *val),
+            Modifier::MaximumSpeed(val) => (StatType::MaximumSpeed, *val),
+            Modifier::Acceleration(val) => (StatType::Acceleration, *val),
+            Modifier::Deceleration(val) => (StatType::Deceleration, *val),
+            Modifier::AbilityCooldown(val) => (StatType::AbilityCooldown, *val),
+            Modifier::MaximumHealth(val) => (StatType::MaximumHealth, *val),
+            Modifier::ConsumableAttractorRadius(val) => (StatType::ConsumableAttractorRadius, *val),
+            Modifier::ConsumableAttractorAcceleration(val) => {
+                (StatType::ConsumableAttractorAcceleration, *val)
+            }
+            Modifier::ItemAttractorRadius(val) => (StatType::ItemAttractorRadius, *val),
+            Modifier::ItemAttractorAcceleration(val) => (StatType::ItemAttractorAcceleration, *val),
+            Modifier::BlastAttractorRadius(val) => (StatType::BlastAttractorRadius, *val),
+            Modifier::BlastAttractorAcceleration(val) => {
+                (StatType::BlastAttractorAcceleration, *val)
+            }
+            Modifier::StatMultiplier(stat_type, val) => {
+                return Some((*stat_type, StatChange::Multiply(*val)))
+            }
+            _ => return None,
+        };
+
+        Some((stat_type, StatChange::Add(val)))
+    }
 }
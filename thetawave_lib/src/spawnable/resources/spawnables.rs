@@ -1,12 +1,15 @@
 use crate::{
-    components::HealthComponent,
+    components::{Faction, FactionComponent, HealthComponent},
     entities::{ConsumableType, EffectType, ItemType, MobType},
-    motion::components::{Hitbox2DComponent, Motion2DComponent},
+    motion::components::{Hitbox2DComponent, Motion2DComponent, TargetComponent},
     resources::{SpriteRenderData, SpriteSheetsResource},
     spawn::components::{
         AutoSpawnerComponent, DespawnAtBorderComponent, DespawnTimeLimitComponent,
+        HealthThresholdSpawnerComponent, MobSpawn, TimedMobSpawnerComponent,
+    },
+    spawnable::components::{
+        ConsumableComponent, EliteComponent, EscortComponent, ItemComponent, MobComponent,
     },
-    spawnable::components::{ConsumableComponent, ItemComponent, MobComponent},
     visual::components::{AnimationComponent, FadeComponent},
     weapons::components::{AutoFireComponent, BlasterComponent},
 };
@@ -92,9 +95,91 @@ pub struct ItemsResource {
     pub random_initial_motion: RandomMotionRange2D,
     /// Item types mapped to their unique item data
     pub item_entities: HashMap<ItemType, ItemEntityData>,
+    /// Rarity tiers mapped to their weights, prices and colors
+    #[serde(default)]
+    pub rarities: HashMap<ItemRarity, RarityData>,
+}
+
+/// Rarity tier of an item
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+pub enum ItemRarity {
+    Common,
+    Rare,
+    Legendary,
+}
+
+impl Default for ItemRarity {
+    fn default() -> Self {
+        ItemRarity::Common
+    }
+}
+
+/// Data shared by all items of a rarity tier
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct RarityData {
+    /// Weight of the tier's items in the store and drop tables
+    pub weight: f32,
+    /// Multiplier of the weight for each phase, the last multiplier is used for later phases
+    #[serde(default)]
+    pub phase_weights: Vec<f32>,
+    /// Multiplier of the price of the tier's items
+    pub price_multiplier: f32,
+    /// Frame drawn around the tier's items in the store and as drops
+    #[serde(default)]
+    pub frame: Option<SpriteRenderData>,
+}
+
+impl RarityData {
+    /// Weight of the tier's items during the given phase
+    pub fn weight(&self, phase_idx: usize) -> f32 {
+        let phase_weight = self
+            .phase_weights
+            .get(phase_idx)
+            .or_else(|| self.phase_weights.last())
+            .copied()
+            .unwrap_or(1.0);
+
+        self.weight * phase_weight
+    }
 }
 
 impl ItemsResource {
+    /// Weight of the item's rarity tier during the given phase, items without tier data have a
+    /// weight of 1
+    pub fn rarity_weight(&self, item_type: &ItemType, phase_idx: usize) -> f32 {
+        self.rarities
+            .get(&self.item_entities[item_type].rarity)
+            .map(|rarity_data| rarity_data.weight(phase_idx))
+            .unwrap_or(1.0)
+    }
+
+    /// Price of the item after the price multiplier of its rarity tier
+    pub fn price(&self, item_type: &ItemType) -> usize {
+        let item_data = &self.item_entities[item_type];
+        let price_multiplier = self
+            .rarities
+            .get(&item_data.rarity)
+            .map(|rarity_data| rarity_data.price_multiplier)
+            .unwrap_or(1.0);
+
+        (item_data.item_component.price as f32 * price_multiplier).round() as usize
+    }
+
+    /// Frame sprite of the item's rarity tier, tiers without a frame return none
+    pub fn frame_sprite_render(
+        &self,
+        item_type: &ItemType,
+        spritesheets_resource: &SpriteSheetsResource,
+    ) -> Option<SpriteRender> {
+        self.rarities
+            .get(&self.item_entities[item_type].rarity)
+            .and_then(|rarity_data| rarity_data.frame.as_ref())
+            .map(|frame| SpriteRender {
+                sprite_sheet: spritesheets_resource.spritesheets[&frame.spritesheet].clone(),
+                sprite_number: frame.initial_index,
+            })
+    }
+
     /// Spawn an item entity
     pub fn spawn_item(
         &self,
@@ -143,6 +228,22 @@ impl ItemsResource {
         if let Some(animation_component) = item_data.animation_component.clone() {
             lazy_update.insert(item_entity, animation_component);
         }
+
+        // spawn the rarity frame as a child of the item entity
+        if let Some(frame_sprite_render) =
+            self.frame_sprite_render(item_type, spritesheets_resource)
+        {
+            let mut frame_transform = Transform::default();
+            frame_transform.set_translation_z(0.1);
+
+            lazy_update
+                .create_entity(entities)
+                .with(Parent::new(item_entity))
+                .with(frame_transform)
+                .with(frame_sprite_render)
+                .with(Transparent)
+                .build();
+        }
     }
 }
 
@@ -162,6 +263,70 @@ impl MobsResource {
         spritesheets_resource: &ReadExpect<SpriteSheetsResource>,
         entities: &Entities,
         lazy_update: &ReadExpect<LazyUpdate>,
+    ) -> Entity {
+        self.build_mob(
+            mob_type,
+            None,
+            None,
+            spawn_transform,
+            spritesheets_resource,
+            entities,
+            lazy_update,
+        )
+    }
+
+    /// Spawn a mob entity with an optional initial velocity
+    pub fn spawn_mob_with_velocity(
+        &self,
+        mob_type: &MobType,
+        initial_velocity: Option<Vector2<f32>>,
+        spawn_transform: Transform,
+        spritesheets_resource: &ReadExpect<SpriteSheetsResource>,
+        entities: &Entities,
+        lazy_update: &ReadExpect<LazyUpdate>,
+    ) -> Entity {
+        self.build_mob(
+            mob_type,
+            None,
+            initial_velocity,
+            spawn_transform,
+            spritesheets_resource,
+            entities,
+            lazy_update,
+        )
+    }
+
+    /// Spawn a mob entity with elite affixes applied
+    pub fn spawn_elite_mob(
+        &self,
+        mob_type: &MobType,
+        elite_component: EliteComponent,
+        spawn_transform: Transform,
+        spritesheets_resource: &ReadExpect<SpriteSheetsResource>,
+        entities: &Entities,
+        lazy_update: &ReadExpect<LazyUpdate>,
+    ) -> Entity {
+        self.build_mob(
+            mob_type,
+            Some(elite_component),
+            None,
+            spawn_transform,
+            spritesheets_resource,
+            entities,
+            lazy_update,
+        )
+    }
+
+    /// Build a mob entity from its data, an optional elite component and an optional initial velocity
+    fn build_mob(
+        &self,
+        mob_type: &MobType,
+        elite_component: Option<EliteComponent>,
+        initial_velocity: Option<Vector2<f32>>,
+        spawn_transform: Transform,
+        spritesheets_resource: &ReadExpect<SpriteSheetsResource>,
+        entities: &Entities,
+        lazy_update: &ReadExpect<LazyUpdate>,
     ) -> Entity {
         let mob_data = &self.mob_entities[mob_type];
 
@@ -188,18 +353,41 @@ impl MobsResource {
             }
         }
 
+        if let Some(initial_velocity) = initial_velocity {
+            motion2d_component.velocity = initial_velocity;
+        }
+
+        let mut mob_component = mob_data.mob_component.clone();
+        let mut health_component = mob_data.health_component.clone();
+
+        if let Some(elite_component) = &elite_component {
+            elite_component.apply(
+                &mut mob_component,
+                &mut health_component,
+                &mut motion2d_component,
+            );
+        }
+
         let mob_entity = lazy_update
             .create_entity(entities)
             .with(mob_sprite_render)
-            .with(mob_data.mob_component.clone())
+            .with(mob_component)
             .with(mob_data.hitbox_component.clone())
             .with(motion2d_component)
-            .with(mob_data.health_component.clone())
+            .with(health_component)
+            .with(FactionComponent {
+                faction: Faction::from_mob_type(mob_type),
+            })
             .with(mob_data.despawn_component.clone())
             .with(spawn_transform)
             .with(Transparent)
             .build();
 
+        if let Some(elite_component) = elite_component {
+            lazy_update.insert(mob_entity, Tint(elite_component.tint));
+            lazy_update.insert(mob_entity, elite_component);
+        }
+
         if let Some(animation_component) = mob_data.animation_component.clone() {
             lazy_update.insert(mob_entity, animation_component);
         }
@@ -212,6 +400,20 @@ impl MobsResource {
         if let Some(auto_child_entity_spawner_component) = mob_data.auto_spawner_component.clone() {
             lazy_update.insert(mob_entity, auto_child_entity_spawner_component);
         }
+        if let Some(escort_component) = mob_data.escort_component.clone() {
+            lazy_update.insert(mob_entity, escort_component);
+        }
+        if let Some(target_component) = mob_data.target_component.clone() {
+            lazy_update.insert(mob_entity, target_component);
+        }
+        if let Some(timed_spawner_component) = mob_data.timed_spawner_component.clone() {
+            lazy_update.insert(mob_entity, timed_spawner_component);
+        }
+        if let Some(health_threshold_spawner_component) =
+            mob_data.health_threshold_spawner_component.clone()
+        {
+            lazy_update.insert(mob_entity, health_threshold_spawner_component);
+        }
 
         // spawn thruster entity as child of mob entity
         if let Some(thruster_data) = mob_data.thruster_data.clone() {
@@ -356,6 +558,24 @@ pub struct MobEntityData {
     pub despawn_component: DespawnAtBorderComponent,
     /// Optional auto-spawner component
     pub auto_spawner_component: Option<AutoSpawnerComponent>,
+    /// Optional escort component
+    #[serde(default)]
+    pub escort_component: Option<EscortComponent>,
+    /// Optional target component
+    #[serde(default)]
+    pub target_component: Option<TargetComponent>,
+    /// Target component given to the mob during phases with a preferred enemy target
+    #[serde(default)]
+    pub phase_target_component: Option<TargetComponent>,
+    /// Optional timed spawner component
+    #[serde(default)]
+    pub timed_spawner_component: Option<TimedMobSpawnerComponent>,
+    /// Optional health threshold spawner component
+    #[serde(default)]
+    pub health_threshold_spawner_component: Option<HealthThresholdSpawnerComponent>,
+    /// Mobs spawned when the mob is destroyed
+    #[serde(default)]
+    pub death_spawns: Vec<MobSpawn>,
     /// Optional thruster entity data
     pub thruster_data: Option<ThrusterEntityData>,
     /// Optional random 2D motion range
@@ -374,6 +594,15 @@ pub struct ThrusterEntityData {
 /// Unique item entity data
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct ItemEntityData {
+    /// Name shown in the store
+    #[serde(default)]
+    pub name: String,
+    /// Description of the item's effects shown in the store
+    #[serde(default)]
+    pub description: String,
+    /// Rarity tier of the item
+    #[serde(default)]
+    pub rarity: ItemRarity,
     /// Sprite data
     pub sprite_render_data: SpriteRenderData,
     /// Item component
@@ -385,6 +614,12 @@ pub struct ItemEntityData {
 /// Unique consumable entity data
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct ConsumableEntityData {
+    /// Name shown in the store
+    #[serde(default)]
+    pub name: String,
+    /// Description of the consumable's effects shown in the store
+    #[serde(default)]
+    pub description: String,
     /// Sprite data
     pub sprite_render_data: SpriteRenderData,
     /// Consumable component
@@ -1,12 +1,15 @@
 use crate::{
     audio::Sounds,
-    components::HealthComponent,
+    components::{HealthComponent, StatSheetComponent},
+    constants::ELITE_SPLIT_SPACING,
     entities::EffectType,
     events::{MobDestroyedEvent, PlayAudioEvent},
-    resources::{DropTablesResource, SpriteSheetsResource},
+    resources::{DropTablesResource, PhaseManagerResource, SpriteSheetsResource},
     spawnable::{
-        components::MobComponent,
-        resources::{ConsumablesResource, EffectsResource, ItemsResource, MobsResource},
+        components::{EliteComponent, MobComponent},
+        resources::{
+            ConsumablesResource, EffectsResource, ItemsResource, MobsResource, StatClampsResource,
+        },
     },
 };
 use amethyst::{
@@ -62,6 +65,7 @@ impl<'s> System<'s> for MobDestroyedSystem {
         Entities<'s>,
         ReadStorage<'s, Transform>,
         ReadStorage<'s, MobComponent>,
+        ReadStorage<'s, EliteComponent>,
         ReadExpect<'s, DropTablesResource>,
         ReadExpect<'s, ConsumablesResource>,
         ReadExpect<'s, MobsResource>,
@@ -71,6 +75,9 @@ impl<'s> System<'s> for MobDestroyedSystem {
         ReadExpect<'s, LazyUpdate>,
         Write<'s, EventChannel<PlayAudioEvent>>,
         ReadExpect<'s, Sounds>,
+        Read<'s, PhaseManagerResource>,
+        ReadStorage<'s, StatSheetComponent>,
+        ReadExpect<'s, StatClampsResource>,
     );
 
     /// Sets up event readers
@@ -91,6 +98,7 @@ impl<'s> System<'s> for MobDestroyedSystem {
             entities,
             transforms,
             mobs,
+            elites,
             drop_tables_resource,
             consumables_resource,
             mobs_resource,
@@ -100,8 +108,17 @@ impl<'s> System<'s> for MobDestroyedSystem {
             lazy_update,
             mut play_audio_channel,
             sounds,
+            phase_manager,
+            stat_sheets,
+            stat_clamps_resource,
         ): Self::SystemData,
     ) {
+        // drops are shared by all players and use the luck of the luckiest player
+        let luck = stat_sheets
+            .join()
+            .map(|stat_sheet| stat_sheet.luck(&stat_clamps_resource))
+            .fold(0.0, f32::max);
+
         for event in mob_destroyed_event_channel.read(self.event_reader.as_mut().unwrap()) {
             let mob_transform = transforms.get(event.mob).unwrap();
             let mob_component = mobs.get(event.mob).unwrap();
@@ -135,6 +152,8 @@ impl<'s> System<'s> for MobDestroyedSystem {
             }
             mob_component.drop_rolls.spawn(
                 mob_transform.clone(),
+                phase_manager.phase_idx,
+                luck,
                 &drop_tables_resource,
                 &consumables_resource,
                 &mobs_resource,
@@ -145,6 +164,51 @@ impl<'s> System<'s> for MobDestroyedSystem {
                 &lazy_update,
             );
 
+            // spawn mobs released on death
+            for mob_spawn in mobs_resource.mob_entities[&mob_component.mob_type]
+                .death_spawns
+                .iter()
+            {
+                mob_spawn.spawn(
+                    mob_transform,
+                    &spritesheets_resource,
+                    &mobs_resource,
+                    &entities,
+                    &lazy_update,
+                );
+            }
+
+            if let Some(elite) = elites.get(event.mob) {
+                // drop items stolen by cursed elite mobs
+                for item_type in elite.stolen_items.iter() {
+                    items_resource.spawn_item(
+                        item_type,
+                        true,
+                        mob_transform.clone(),
+                        &spritesheets_resource,
+                        &entities,
+                        &lazy_update,
+                    );
+                }
+
+                // spawn regular copies of splitting elite mobs
+                let split_count = elite.splitting_count();
+                for i in 0..split_count {
+                    let mut split_transform = mob_transform.clone();
+                    split_transform.prepend_translation_x(
+                        (i as f32 - (split_count - 1) as f32 / 2.0) * ELITE_SPLIT_SPACING,
+                    );
+
+                    mobs_resource.spawn_mob(
+                        &mob_component.mob_type,
+                        split_transform,
+                        &spritesheets_resource,
+                        &entities,
+                        &lazy_update,
+                    );
+                }
+            }
+
             entities.delete(event.mob).expect("unable to delete entity");
         }
     }
@@ -1,16 +1,20 @@
 use crate::{
     components::{
-        AttractorCategory, AttractorComponent, BarrelRollAbilityComponent, HealthComponent,
-        PlayerComponent,
+        AttractorComponent, BarrelRollAbilityComponent, HealthComponent, PlayerComponent,
+        StatSheetComponent,
     },
-    entities::SpawnableType,
-    events::{ConsumableGetEvent, ItemGetEvent},
+    entities::{ItemType, SpawnableType},
+    events::{AnnouncementEvent, ConsumableGetEvent, ItemGetEvent, ItemRemoveEvent},
     motion::components::Motion2DComponent,
-    resources::DefenseResource,
-    spawnable::resources::{ConsumableModifiersResource, ItemModifiersResource, Modifier},
-    weapons::components::{BlasterComponent, ManualFireComponent},
+    resources::{DefenseResource, SecondaryWeaponsResource, StoreResource},
+    spawnable::resources::{
+        BuffsResource, ConsumableModifiersResource, ItemModifiersResource, Modifier,
+        StatClampsResource, SynergiesResource,
+    },
+    weapons::components::{BlasterComponent, ManualFireComponent, SecondaryWeaponComponent},
 };
 use amethyst::{
+    core::timing::Time,
     ecs::*,
     ecs::{Read, ReadExpect, System, WriteStorage},
     shrev::EventChannel,
@@ -23,6 +27,8 @@ pub struct ModifiersSystem {
     item_get_event_reader: Option<ReaderId<ItemGetEvent>>,
     /// Reads from the consumable get event channel
     consumable_get_event_reader: Option<ReaderId<ConsumableGetEvent>>,
+    /// Reads from the item remove event channel
+    item_remove_event_reader: Option<ReaderId<ItemRemoveEvent>>,
 }
 
 impl<'s> System<'s> for ModifiersSystem {
@@ -30,6 +36,7 @@ impl<'s> System<'s> for ModifiersSystem {
     type SystemData = (
         Read<'s, EventChannel<ItemGetEvent>>,
         Read<'s, EventChannel<ConsumableGetEvent>>,
+        Read<'s, EventChannel<ItemRemoveEvent>>,
         WriteStorage<'s, BarrelRollAbilityComponent>,
         WriteStorage<'s, HealthComponent>,
         WriteStorage<'s, BlasterComponent>,
@@ -37,9 +44,18 @@ impl<'s> System<'s> for ModifiersSystem {
         WriteStorage<'s, Motion2DComponent>,
         WriteStorage<'s, AttractorComponent>,
         WriteStorage<'s, PlayerComponent>,
+        WriteStorage<'s, SecondaryWeaponComponent>,
+        WriteStorage<'s, StatSheetComponent>,
         ReadExpect<'s, ItemModifiersResource>,
         ReadExpect<'s, ConsumableModifiersResource>,
         WriteExpect<'s, DefenseResource>,
+        ReadExpect<'s, SecondaryWeaponsResource>,
+        ReadExpect<'s, StatClampsResource>,
+        ReadExpect<'s, BuffsResource>,
+        Read<'s, Time>,
+        ReadExpect<'s, SynergiesResource>,
+        Write<'s, EventChannel<AnnouncementEvent>>,
+        WriteExpect<'s, StoreResource>,
     );
 
     /// Sets up event readers
@@ -55,6 +71,11 @@ impl<'s> System<'s> for ModifiersSystem {
                 .fetch_mut::<EventChannel<ConsumableGetEvent>>()
                 .register_reader(),
         );
+        self.item_remove_event_reader = Some(
+            world
+                .fetch_mut::<EventChannel<ItemRemoveEvent>>()
+                .register_reader(),
+        );
     }
 
     /// System game logic
@@ -63,6 +84,7 @@ impl<'s> System<'s> for ModifiersSystem {
         (
             item_get_event_channel,
             consumable_get_event_channel,
+            item_remove_event_channel,
             mut barrel_roll_ability_components,
             mut health_components,
             mut blaster_components,
@@ -70,12 +92,51 @@ impl<'s> System<'s> for ModifiersSystem {
             mut motion_2d_components,
             mut attractor_components,
             mut player_components,
+            mut secondary_weapon_components,
+            mut stat_sheet_components,
             item_modifiers_resource,
             consumable_modifiers_resource,
             mut defense_resource,
+            secondary_weapons_resource,
+            stat_clamps_resource,
+            buffs_resource,
+            time,
+            synergies_resource,
+            mut announcement_event_channel,
+            mut store_resource,
         ): Self::SystemData,
     ) {
+        // recompute stats of players whose buffs expired
+        for (stat_sheet, blaster, manual_fire, motion2d, health, barrel_roll_ability, attractor) in
+            (
+                &mut stat_sheet_components,
+                &mut blaster_components,
+                &mut manual_fire_components,
+                &mut motion_2d_components,
+                &mut health_components,
+                &mut barrel_roll_ability_components,
+                &mut attractor_components,
+            )
+                .join()
+        {
+            if stat_sheet.update_buffs(time.delta_seconds()) {
+                stat_sheet.apply(
+                    &stat_clamps_resource,
+                    blaster,
+                    manual_fire,
+                    motion2d,
+                    health,
+                    barrel_roll_ability,
+                    attractor,
+                );
+            }
+        }
+
+        // players whose held items changed this frame
+        let mut changed_players = vec![];
+
         for event in item_get_event_channel.read(self.item_get_event_reader.as_mut().unwrap()) {
+            changed_players.push(event.player_entity);
             apply_modifiers(
                 &item_modifiers_resource[&event.item_type],
                 event.player_entity,
@@ -87,7 +148,13 @@ impl<'s> System<'s> for ModifiersSystem {
                 &mut motion_2d_components,
                 &mut attractor_components,
                 &mut player_components,
+                &mut secondary_weapon_components,
+                &mut stat_sheet_components,
                 &mut defense_resource,
+                &mut store_resource,
+                &secondary_weapons_resource,
+                &stat_clamps_resource,
+                &buffs_resource,
             )
         }
 
@@ -105,12 +172,53 @@ impl<'s> System<'s> for ModifiersSystem {
                 &mut motion_2d_components,
                 &mut attractor_components,
                 &mut player_components,
+                &mut secondary_weapon_components,
+                &mut stat_sheet_components,
                 &mut defense_resource,
+                &mut store_resource,
+                &secondary_weapons_resource,
+                &stat_clamps_resource,
+                &buffs_resource,
             )
         }
 
-        // TODO: remove item effects for item remove event
-        // for event in item_remove_event_channel...
+        for event in item_remove_event_channel.read(self.item_remove_event_reader.as_mut().unwrap())
+        {
+            changed_players.push(event.player_entity);
+            remove_item_modifiers(
+                event.player_entity,
+                &event.item_type,
+                &mut barrel_roll_ability_components,
+                &mut health_components,
+                &mut blaster_components,
+                &mut manual_fire_components,
+                &mut motion_2d_components,
+                &mut attractor_components,
+                &mut player_components,
+                &mut secondary_weapon_components,
+                &mut stat_sheet_components,
+                &mut defense_resource,
+                &mut store_resource,
+                &stat_clamps_resource,
+            )
+        }
+
+        for player_entity in changed_players {
+            update_synergies(
+                player_entity,
+                &mut barrel_roll_ability_components,
+                &mut health_components,
+                &mut blaster_components,
+                &mut manual_fire_components,
+                &mut motion_2d_components,
+                &mut attractor_components,
+                &player_components,
+                &mut stat_sheet_components,
+                &stat_clamps_resource,
+                &synergies_resource,
+                &mut announcement_event_channel,
+            )
+        }
     }
 }
 
@@ -126,7 +234,13 @@ pub fn apply_modifiers(
     motion_2d_components: &mut WriteStorage<Motion2DComponent>,
     attractor_components: &mut WriteStorage<AttractorComponent>,
     player_components: &mut WriteStorage<PlayerComponent>,
+    secondary_weapon_components: &mut WriteStorage<SecondaryWeaponComponent>,
+    stat_sheet_components: &mut WriteStorage<StatSheetComponent>,
     defense_resource: &mut WriteExpect<DefenseResource>,
+    store_resource: &mut WriteExpect<StoreResource>,
+    secondary_weapons_resource: &ReadExpect<SecondaryWeaponsResource>,
+    stat_clamps_resource: &ReadExpect<StatClampsResource>,
+    buffs_resource: &ReadExpect<BuffsResource>,
 ) {
     let player_barrel_roll_ability = barrel_roll_ability_components
         .get_mut(player_entity)
@@ -137,72 +251,52 @@ pub fn apply_modifiers(
     let player_motion2d = motion_2d_components.get_mut(player_entity).unwrap();
     let player_attractor = attractor_components.get_mut(player_entity).unwrap();
     let player_component = player_components.get_mut(player_entity).unwrap();
+    let player_secondary_weapon = secondary_weapon_components.get_mut(player_entity).unwrap();
+    let player_stat_sheet = stat_sheet_components.get_mut(player_entity).unwrap();
+
+    // stats and flags are recomputed by the stat sheet from all active modifiers,
+    // consumables with only one-time effects are not kept on the sheet
+    if matches!(spawnable_type, SpawnableType::Item(_))
+        || modifiers
+            .iter()
+            .any(|modifier| modifier.stat_change().is_some() || modifier.is_flag())
+    {
+        player_stat_sheet.add_modifiers(spawnable_type.clone(), modifiers.clone());
+    }
 
-    match spawnable_type {
-        SpawnableType::Item(item_type) => {
-            player_component.items.push(item_type);
+    for modifier in modifiers.iter() {
+        if let Modifier::Buff(buff_type) = modifier {
+            player_stat_sheet.add_buff(*buff_type, &buffs_resource[buff_type]);
         }
-
-        SpawnableType::Consumable(_consumable_type) => {}
-
-        _ => panic!("only consumables and items can have modifiers"),
     }
+    player_stat_sheet.apply(
+        stat_clamps_resource,
+        player_blaster,
+        player_manual_fire,
+        player_motion2d,
+        player_health,
+        player_barrel_roll_ability,
+        player_attractor,
+    );
 
     for modifier in modifiers.iter() {
         match modifier {
-            Modifier::BarrelImmunity(val) => {
-                player_barrel_roll_ability.steel_barrel = *val;
-            }
-
-            Modifier::ProjectileCount(val) => {
-                player_blaster.count += val;
-            }
-
-            Modifier::ProjectileFirePeriod(val) => {
-                player_manual_fire.period += val;
-            }
-
-            Modifier::ProjectileDamage(val) => {
-                player_blaster.damage += val;
-            }
-
-            Modifier::MaximumSpeed(val) => {
-                player_motion2d.max_speed.x += val;
-                player_motion2d.max_speed.y += val;
-            }
-
-            Modifier::CriticalDamageChance(val) => {
-                player_blaster.crit_chance += val;
-            }
-
-            Modifier::PoisonChance(val) => {
-                player_blaster.poison_chance += val;
-            }
-
-            Modifier::AbilityCooldown(val) => {
-                player_barrel_roll_ability.execute_cooldown += val;
-            }
-
-            Modifier::Acceleration(val) => {
-                player_motion2d.acceleration.x += val;
-                player_motion2d.acceleration.y += val;
-            }
-
-            Modifier::Deceleration(val) => {
-                player_motion2d.deceleration.x += val;
-                player_motion2d.deceleration.y += val;
+            Modifier::Health(val) => {
+                player_health.value += val;
             }
 
-            Modifier::MaximumHealth(val) => {
-                player_health.max_value += val;
+            Modifier::SecondaryWeapon(weapon_type) => {
+                player_secondary_weapon.unlock(secondary_weapons_resource[weapon_type].clone());
             }
 
-            Modifier::Health(val) => {
-                player_health.value += val;
+            Modifier::SecondaryAmmo(val) => {
+                if let Some(weapon) = player_secondary_weapon.equipped_weapon_mut() {
+                    weapon.add_ammo(*val);
+                }
             }
 
-            Modifier::ProjectileSize(val) => {
-                player_blaster.size_multiplier += val;
+            Modifier::SecondaryMaximumAmmo(val) => {
+                player_secondary_weapon.add_max_ammo(*val);
             }
 
             Modifier::MaximumDefense(val) => {
@@ -221,77 +315,170 @@ pub fn apply_modifiers(
                 player_component.money += val;
             }
 
-            Modifier::ConsumableAttractorRadius(val) => {
-                if let Some(consumable_attract_data) = player_attractor
-                    .attracted_spawnables
-                    .get_mut(&AttractorCategory::Consumable)
-                {
-                    consumable_attract_data.radius += val;
-                }
+            Modifier::Bombs(val) => {
+                player_component.bombs += val;
             }
 
-            Modifier::ItemAttractorRadius(val) => {
-                if let Some(item_attract_data) = player_attractor
-                    .attracted_spawnables
-                    .get_mut(&AttractorCategory::Item)
-                {
-                    item_attract_data.radius += val;
-                }
+            Modifier::StoreSlots(val) => {
+                store_resource.change_bonus_slots(*val as isize);
             }
 
-            Modifier::ConsumableAttractorAcceleration(val) => {
-                if let Some(consumable_attract_data) = player_attractor
-                    .attracted_spawnables
-                    .get_mut(&AttractorCategory::Consumable)
-                {
-                    consumable_attract_data.acceleration += val;
-                }
-            }
+            _ => {}
+        }
+    }
 
-            Modifier::ItemAttractorAcceleration(val) => {
-                if let Some(item_attract_data) = player_attractor
-                    .attracted_spawnables
-                    .get_mut(&AttractorCategory::Item)
-                {
-                    item_attract_data.acceleration += val;
-                }
-            }
+    match spawnable_type {
+        SpawnableType::Item(item_type) => {
+            player_component.items.push(item_type);
+        }
+
+        SpawnableType::Consumable(_consumable_type) => {}
+
+        _ => panic!("only consumables and items can have modifiers"),
+    }
+}
+
+/// Remove the modifiers of an item held by the player and remove it from the player's items
+pub fn remove_item_modifiers(
+    player_entity: Entity,
+    item_type: &ItemType,
+    barrel_roll_ability_components: &mut WriteStorage<BarrelRollAbilityComponent>,
+    health_components: &mut WriteStorage<HealthComponent>,
+    blaster_components: &mut WriteStorage<BlasterComponent>,
+    manual_fire_components: &mut WriteStorage<ManualFireComponent>,
+    motion_2d_components: &mut WriteStorage<Motion2DComponent>,
+    attractor_components: &mut WriteStorage<AttractorComponent>,
+    player_components: &mut WriteStorage<PlayerComponent>,
+    secondary_weapon_components: &mut WriteStorage<SecondaryWeaponComponent>,
+    stat_sheet_components: &mut WriteStorage<StatSheetComponent>,
+    defense_resource: &mut WriteExpect<DefenseResource>,
+    store_resource: &mut WriteExpect<StoreResource>,
+    stat_clamps_resource: &ReadExpect<StatClampsResource>,
+) {
+    let player_component = if let Some(player_component) = player_components.get_mut(player_entity)
+    {
+        player_component
+    } else {
+        return;
+    };
+
+    // remove the most recently collected copy of the item
+    let item_index = if let Some(item_index) = player_component
+        .items
+        .iter()
+        .rposition(|item| item == item_type)
+    {
+        item_index
+    } else {
+        return;
+    };
+    player_component.items.remove(item_index);
+
+    let player_stat_sheet = stat_sheet_components.get_mut(player_entity).unwrap();
+    let modifiers = player_stat_sheet
+        .remove_modifiers(&SpawnableType::Item(item_type.clone()))
+        .unwrap_or_default();
+
+    let player_barrel_roll_ability = barrel_roll_ability_components
+        .get_mut(player_entity)
+        .unwrap();
+    let player_health = health_components.get_mut(player_entity).unwrap();
+    let player_blaster = blaster_components.get_mut(player_entity).unwrap();
+    let player_manual_fire = manual_fire_components.get_mut(player_entity).unwrap();
+    let player_motion2d = motion_2d_components.get_mut(player_entity).unwrap();
+    let player_attractor = attractor_components.get_mut(player_entity).unwrap();
+    let player_secondary_weapon = secondary_weapon_components.get_mut(player_entity).unwrap();
+
+    player_stat_sheet.apply(
+        stat_clamps_resource,
+        player_blaster,
+        player_manual_fire,
+        player_motion2d,
+        player_health,
+        player_barrel_roll_ability,
+        player_attractor,
+    );
 
-            Modifier::BlastAttractorIsActive(val) => {
-                if let Some(blast_attract_data) = player_attractor
-                    .attracted_spawnables
-                    .get_mut(&AttractorCategory::Blast)
-                {
-                    blast_attract_data.is_active = *val;
+    // one-time gains are kept, only lasting effects outside the stat sheet are reversed
+    for modifier in modifiers.iter() {
+        match modifier {
+            Modifier::SecondaryWeapon(weapon_type) => {
+                // the weapon stays while another held item grants it
+                let still_granted = player_stat_sheet
+                    .active_modifiers
+                    .iter()
+                    .filter(|(spawnable_type, _)| matches!(spawnable_type, SpawnableType::Item(_)))
+                    .flat_map(|(_, item_modifiers)| item_modifiers.iter())
+                    .any(|item_modifier| match item_modifier {
+                        Modifier::SecondaryWeapon(granted) => granted == weapon_type,
+                        _ => false,
+                    });
+                if !still_granted {
+                    player_secondary_weapon.lock(weapon_type);
                 }
             }
 
-            Modifier::BlastAttractorShouldRepel(val) => {
-                if let Some(blast_attract_data) = player_attractor
-                    .attracted_spawnables
-                    .get_mut(&AttractorCategory::Blast)
-                {
-                    blast_attract_data.should_repel = *val;
-                }
+            Modifier::SecondaryMaximumAmmo(val) => {
+                player_secondary_weapon.remove_max_ammo(*val);
             }
 
-            Modifier::BlastAttractorAcceleration(val) => {
-                if let Some(blast_attract_data) = player_attractor
-                    .attracted_spawnables
-                    .get_mut(&AttractorCategory::Blast)
-                {
-                    blast_attract_data.acceleration += val;
-                }
+            Modifier::MaximumDefense(val) => {
+                defense_resource.max_defense -= val;
+                defense_resource.constrain();
             }
 
-            Modifier::BlastAttractorRadius(val) => {
-                if let Some(blast_attract_data) = player_attractor
-                    .attracted_spawnables
-                    .get_mut(&AttractorCategory::Blast)
-                {
-                    blast_attract_data.radius += val;
-                }
+            Modifier::StoreSlots(val) => {
+                store_resource.change_bonus_slots(-(*val as isize));
             }
+
+            _ => {}
         }
     }
 }
+
+/// Unlock synergies of the items held by the player, lock synergies missing an item and
+/// announce newly unlocked synergies
+pub fn update_synergies(
+    player_entity: Entity,
+    barrel_roll_ability_components: &mut WriteStorage<BarrelRollAbilityComponent>,
+    health_components: &mut WriteStorage<HealthComponent>,
+    blaster_components: &mut WriteStorage<BlasterComponent>,
+    manual_fire_components: &mut WriteStorage<ManualFireComponent>,
+    motion_2d_components: &mut WriteStorage<Motion2DComponent>,
+    attractor_components: &mut WriteStorage<AttractorComponent>,
+    player_components: &WriteStorage<PlayerComponent>,
+    stat_sheet_components: &mut WriteStorage<StatSheetComponent>,
+    stat_clamps_resource: &ReadExpect<StatClampsResource>,
+    synergies_resource: &ReadExpect<SynergiesResource>,
+    announcement_event_channel: &mut Write<EventChannel<AnnouncementEvent>>,
+) {
+    let (player_component, player_stat_sheet) =
+        if let (Some(player_component), Some(player_stat_sheet)) = (
+            player_components.get(player_entity),
+            stat_sheet_components.get_mut(player_entity),
+        ) {
+            (player_component, player_stat_sheet)
+        } else {
+            return;
+        };
+
+    for synergy_type in
+        player_stat_sheet.update_synergies(&player_component.items, synergies_resource)
+    {
+        announcement_event_channel.single_write(AnnouncementEvent {
+            text: format!("{} unlocked", synergies_resource[&synergy_type].name),
+        });
+    }
+
+    player_stat_sheet.apply(
+        stat_clamps_resource,
+        blaster_components.get_mut(player_entity).unwrap(),
+        manual_fire_components.get_mut(player_entity).unwrap(),
+        motion_2d_components.get_mut(player_entity).unwrap(),
+        health_components.get_mut(player_entity).unwrap(),
+        barrel_roll_ability_components
+            .get_mut(player_entity)
+            .unwrap(),
+        attractor_components.get_mut(player_entity).unwrap(),
+    );
+}
@@ -1,14 +1,22 @@
 use crate::{
-    components::{AbilityDirection, BarrelRollAbilityComponent, BarrierComponent, CooldownAbility},
+    components::{
+        AbilityDirection, BarrelRollAbilityComponent, BarrierComponent, CooldownAbility,
+        DecoyComponent, PlayerComponent, SpecialAbilityComponent, SpecialAbilityType,
+    },
+    constants::{ARENA_MAX_X, ARENA_MAX_Y, ARENA_MIN_X, ARENA_MIN_Y},
     events::PlayerCollisionEvent,
     motion::components::Motion2DComponent,
-    spawnable::components::MobComponent,
+    spawn::components::DespawnTimeLimitComponent,
+    spawnable::components::{BlastComponent, MobComponent},
+    tools::distance,
+    weapons::BlastType,
 };
 use amethyst::{
-    core::timing::Time,
+    core::{math::Vector2, timing::Time, Transform},
     ecs::*,
     ecs::{Join, Read, ReadStorage, System, WriteStorage},
     input::{InputHandler, StringBindings},
+    renderer::{SpriteRender, Transparent},
     shrev::{EventChannel, ReaderId},
 };
 
@@ -19,9 +27,11 @@ pub struct BarrelRollAbilitySystem {
 
 impl<'s> System<'s> for BarrelRollAbilitySystem {
     type SystemData = (
+        Entities<'s>,
         Read<'s, EventChannel<PlayerCollisionEvent>>,
         Read<'s, InputHandler<StringBindings>>,
         Read<'s, Time>,
+        ReadStorage<'s, PlayerComponent>,
         WriteStorage<'s, BarrelRollAbilityComponent>,
         WriteStorage<'s, Motion2DComponent>,
         ReadStorage<'s, MobComponent>,
@@ -40,29 +50,44 @@ impl<'s> System<'s> for BarrelRollAbilitySystem {
     fn run(
         &mut self,
         (
+            entities,
             collision_event_channel,
             input,
             time,
+            players,
             mut barrel_roll_abilities,
             mut motion2ds,
             mobs,
             barriers,
         ): Self::SystemData,
     ) {
-        for (barrel_roll_ability, motion2d) in (&mut barrel_roll_abilities, &mut motion2ds).join() {
+        // players that collided with a mob or barrier this frame
+        let colliding_players: Vec<Entity> = collision_event_channel
+            .read(self.event_reader.as_mut().unwrap())
+            .filter(|event| {
+                mobs.get(event.colliding_entity).is_some()
+                    || barriers.get(event.colliding_entity).is_some()
+            })
+            .map(|event| event.player_entity)
+            .collect();
+
+        for (entity, player, barrel_roll_ability, motion2d) in (
+            &*entities,
+            &players,
+            &mut barrel_roll_abilities,
+            &mut motion2ds,
+        )
+            .join()
+        {
             // execute barrel roll on input down
-            barrel_roll_ability.execute_action(&input);
+            barrel_roll_ability.execute_action(&input, player.player_index);
 
             // update ability and timers
             barrel_roll_ability.update(time.delta_seconds());
 
             // change direction if colliding with mob
-            for event in collision_event_channel.read(self.event_reader.as_mut().unwrap()) {
-                if let Some(_mob) = mobs.get(event.colliding_entity) {
-                    barrel_roll_ability.invert_direction();
-                } else if let Some(_barrier) = barriers.get(event.colliding_entity) {
-                    barrel_roll_ability.invert_direction();
-                }
+            if colliding_players.contains(&entity) {
+                barrel_roll_ability.invert_direction();
             }
 
             // change velocity if barrel rolling
@@ -78,3 +103,142 @@ impl<'s> System<'s> for BarrelRollAbilitySystem {
         }
     }
 }
+
+/// Handles activation and effects of special abilities
+pub struct SpecialAbilitySystem;
+
+impl<'s> System<'s> for SpecialAbilitySystem {
+    /// Data used by the system
+    type SystemData = (
+        Entities<'s>,
+        Read<'s, InputHandler<StringBindings>>,
+        Read<'s, Time>,
+        ReadStorage<'s, PlayerComponent>,
+        WriteStorage<'s, SpecialAbilityComponent>,
+        ReadStorage<'s, BlastComponent>,
+        WriteStorage<'s, Motion2DComponent>,
+        WriteStorage<'s, Transform>,
+        ReadStorage<'s, SpriteRender>,
+        ReadExpect<'s, LazyUpdate>,
+    );
+
+    /// System game logic
+    fn run(
+        &mut self,
+        (
+            entities,
+            input,
+            time,
+            players,
+            mut special_abilities,
+            blasts,
+            mut motion2ds,
+            mut transforms,
+            sprite_renders,
+            lazy_update,
+        ): Self::SystemData,
+    ) {
+        // position, radius, and repel speed of every active shield burst
+        let mut shield_bursts: Vec<(Vector2<f32>, f32, f32)> = vec![];
+
+        for (player, special_ability, transform, sprite_render) in (
+            &players,
+            &mut special_abilities,
+            &mut transforms,
+            &sprite_renders,
+        )
+            .join()
+        {
+            // the ability was activated this frame if its cooldown was just started
+            let was_ready = special_ability.execute_timer <= 0.0;
+            special_ability.execute_action(&input, player.player_index);
+            let activated = was_ready && special_ability.execute_timer > 0.0;
+
+            special_ability.update(time.delta_seconds());
+
+            match special_ability.ability_type {
+                SpecialAbilityType::ShieldBurst {
+                    radius,
+                    repel_speed,
+                } => {
+                    if activated || special_ability.is_active() {
+                        shield_bursts.push((
+                            Vector2::new(transform.translation().x, transform.translation().y),
+                            radius,
+                            repel_speed,
+                        ));
+                    }
+                }
+
+                SpecialAbilityType::Teleport { distance } => {
+                    if activated {
+                        // teleport in the steering direction, or forward if not steering
+                        let mut direction = Vector2::new(
+                            input.axis_value(&player.action("player_x")).unwrap_or(0.0) as f32,
+                            input.axis_value(&player.action("player_y")).unwrap_or(0.0) as f32,
+                        );
+                        if direction.norm() == 0.0 {
+                            direction = Vector2::new(0.0, 1.0);
+                        }
+                        let offset = direction.normalize() * distance;
+
+                        transform.set_translation_xyz(
+                            (transform.translation().x + offset.x)
+                                .max(ARENA_MIN_X)
+                                .min(ARENA_MAX_X),
+                            (transform.translation().y + offset.y)
+                                .max(ARENA_MIN_Y)
+                                .min(ARENA_MAX_Y),
+                            transform.translation().z,
+                        );
+                    }
+                }
+
+                SpecialAbilityType::Decoy => {
+                    if activated {
+                        lazy_update
+                            .create_entity(&entities)
+                            .with(sprite_render.clone())
+                            .with(transform.clone())
+                            .with(Transparent)
+                            .with(DecoyComponent)
+                            .with(DespawnTimeLimitComponent {
+                                duration: special_ability.action_duration,
+                            })
+                            .build();
+                    }
+                }
+
+                // time slow fields are applied by the motion system
+                SpecialAbilityType::TimeSlow { .. } => {}
+            }
+        }
+
+        if shield_bursts.is_empty() {
+            return;
+        }
+
+        // push enemy blasts away from active shield bursts
+        for (blast, motion2d, transform) in (&blasts, &mut motion2ds, &transforms).join() {
+            if let BlastType::Enemy = blast.blast_type {
+                let blast_position =
+                    Vector2::new(transform.translation().x, transform.translation().y);
+
+                for (burst_position, radius, repel_speed) in shield_bursts.iter() {
+                    if distance(
+                        blast_position.x,
+                        burst_position.x,
+                        blast_position.y,
+                        burst_position.y,
+                    ) <= *radius
+                    {
+                        let away = blast_position - burst_position;
+                        if away.norm() > 0.0 {
+                            motion2d.velocity = away.normalize() * *repel_speed;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{
+    audio::Sounds,
+    components::{Faction, FactionComponent, HealthComponent, PlayerComponent},
+    constants::{
+        ARENA_HEIGHT, ARENA_MAX_X, ARENA_MAX_Y, ARENA_MIN_X, ARENA_MIN_Y, ARENA_WIDTH,
+        BOMB_FLASH_SCALE, SPACESHIP_BOMB_DAMAGE, SPACESHIP_BOMB_INVULNERABILITY_DURATION,
+    },
+    entities::EffectType,
+    events::PlayAudioEvent,
+    resources::{FactionsResource, SpriteSheetsResource},
+    spawnable::{
+        components::{BlastComponent, MobComponent},
+        resources::EffectsResource,
+    },
+    tools::PressTracker,
+    weapons::BlastType,
+};
+use amethyst::{
+    core::{math::Vector3, Transform},
+    ecs::prelude::{
+        Entities, Join, LazyUpdate, Read, ReadExpect, ReadStorage, System, Write, WriteStorage,
+    },
+    input::{InputHandler, StringBindings},
+    shrev::EventChannel,
+};
+
+/// Handles detonation of screen-clearing bombs
+#[derive(Default)]
+pub struct BombSystem {
+    /// Tracks presses of each player's bomb action
+    bomb_presses: PressTracker,
+}
+
+impl<'s> System<'s> for BombSystem {
+    /// Data used by the system
+    type SystemData = (
+        Entities<'s>,
+        WriteStorage<'s, PlayerComponent>,
+        WriteStorage<'s, HealthComponent>,
+        ReadStorage<'s, MobComponent>,
+        ReadStorage<'s, BlastComponent>,
+        ReadStorage<'s, FactionComponent>,
+        ReadStorage<'s, Transform>,
+        Read<'s, InputHandler<StringBindings>>,
+        ReadExpect<'s, FactionsResource>,
+        ReadExpect<'s, EffectsResource>,
+        ReadExpect<'s, SpriteSheetsResource>,
+        ReadExpect<'s, LazyUpdate>,
+        Write<'s, EventChannel<PlayAudioEvent>>,
+        ReadExpect<'s, Sounds>,
+    );
+
+    /// System game logic
+    fn run(
+        &mut self,
+        (
+            entities,
+            mut players,
+            mut healths,
+            mobs,
+            blasts,
+            factions,
+            transforms,
+            input,
+            factions_resource,
+            effects_resource,
+            sprite_resource,
+            lazy_update,
+            mut play_audio_channel,
+            sounds,
+        ): Self::SystemData,
+    ) {
+        let mut detonated = false;
+        for (player, health) in (&mut players, &mut healths).join() {
+            let bomb_action = input.action_is_down(&player.action("bomb")).unwrap();
+
+            // only detonate once per press
+            let bomb_pressed = self.bomb_presses.pressed(player.player_index, bomb_action);
+
+            if bomb_pressed && player.bombs > 0 {
+                player.bombs -= 1;
+                health.make_invulnerable(SPACESHIP_BOMB_INVULNERABILITY_DURATION);
+                detonated = true;
+            }
+        }
+
+        if !detonated {
+            return;
+        }
+
+        // destroy all enemy blasts
+        for (blast_entity, blast) in (&*entities, &blasts).join() {
+            if let BlastType::Enemy = blast.blast_type {
+                entities
+                    .delete(blast_entity)
+                    .expect("unable to delete entity");
+            }
+        }
+
+        // damage every mob in the arena that allies can damage
+        for (_mob, faction, transform, health) in
+            (&mobs, &factions, &transforms, &mut healths).join()
+        {
+            let position = transform.translation();
+            if position.x < ARENA_MIN_X
+                || position.x > ARENA_MAX_X
+                || position.y < ARENA_MIN_Y
+                || position.y > ARENA_MAX_Y
+            {
+                continue;
+            }
+
+            // skip factions the bomb can't damage so armor isn't used up
+            let bomb_multiplier =
+                factions_resource.damage_multiplier(&Faction::Ally, &faction.faction);
+            if bomb_multiplier <= 0.0 {
+                continue;
+            }
+
+            health.take_damage(SPACESHIP_BOMB_DAMAGE * bomb_multiplier);
+        }
+
+        let mut flash_transform = Transform::default();
+        flash_transform.set_translation_xyz(
+            ARENA_MIN_X + (ARENA_WIDTH / 2.0),
+            ARENA_MIN_Y + (ARENA_HEIGHT / 2.0),
+            0.95,
+        );
+        flash_transform.set_scale(Vector3::new(BOMB_FLASH_SCALE, BOMB_FLASH_SCALE, 1.0));
+
+        effects_resource.spawn_effect(
+            &EffectType::BombFlash,
+            flash_transform,
+            &sprite_resource,
+            &entities,
+            &lazy_update,
+        );
+
+        play_audio_channel.single_write(PlayAudioEvent {
+            source: sounds.sound_effects["explosion"].clone(),
+        });
+    }
+}
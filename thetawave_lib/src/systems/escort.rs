@@ -0,0 +1,78 @@
+use crate::{
+    components::PlayerComponent,
+    events::{MobDestroyedEvent, MobReachedBottomEvent},
+    resources::{DefenseResource, PhaseManagerResource},
+    spawnable::components::EscortComponent,
+};
+use amethyst::{
+    ecs::prelude::{Join, ReadStorage, System, Write, WriteExpect, WriteStorage},
+    ecs::*,
+    shrev::{EventChannel, ReaderId},
+};
+
+#[derive(Default)]
+pub struct EscortSystem {
+    mob_reached_bottom_event_reader: Option<ReaderId<MobReachedBottomEvent>>,
+    mob_destroyed_event_reader: Option<ReaderId<MobDestroyedEvent>>,
+}
+
+impl<'s> System<'s> for EscortSystem {
+    type SystemData = (
+        Read<'s, EventChannel<MobReachedBottomEvent>>,
+        Read<'s, EventChannel<MobDestroyedEvent>>,
+        ReadStorage<'s, EscortComponent>,
+        WriteStorage<'s, PlayerComponent>,
+        WriteExpect<'s, DefenseResource>,
+        Write<'s, PhaseManagerResource>,
+    );
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.mob_reached_bottom_event_reader = Some(
+            world
+                .fetch_mut::<EventChannel<MobReachedBottomEvent>>()
+                .register_reader(),
+        );
+        self.mob_destroyed_event_reader = Some(
+            world
+                .fetch_mut::<EventChannel<MobDestroyedEvent>>()
+                .register_reader(),
+        );
+    }
+
+    fn run(
+        &mut self,
+        (
+            mob_reached_bottom_event_channel,
+            mob_destroyed_event_channel,
+            escorts,
+            mut players,
+            mut defense_resource,
+            mut phase_manager,
+        ): Self::SystemData,
+    ) {
+        // pay out escorted mobs that reached the bottom of the arena
+        for event in mob_reached_bottom_event_channel
+            .read(self.mob_reached_bottom_event_reader.as_mut().unwrap())
+        {
+            if let Some(escort) = escorts.get(event.mob) {
+                for player in (&mut players).join() {
+                    player.money += escort.reward_money;
+                }
+
+                phase_manager.escorts_completed += 1;
+            }
+        }
+
+        // damage defense for escorted mobs that were destroyed
+        for event in
+            mob_destroyed_event_channel.read(self.mob_destroyed_event_reader.as_mut().unwrap())
+        {
+            if let Some(escort) = escorts.get(event.mob) {
+                defense_resource.value -= escort.destroyed_defense_damage;
+            }
+        }
+
+        defense_resource.constrain();
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{
+    events::ConsumableGetEvent,
+    resources::{PhaseManagerResource, SaveProfileResource},
+    spawnable::resources::{ConsumableModifiersResource, Modifier},
+};
+use amethyst::{
+    ecs::*,
+    ecs::{Read, ReadExpect, System, World, WriteExpect},
+    shrev::{EventChannel, ReaderId},
+};
+
+/// Records currency collected and phases reached in the save profile
+#[derive(Default)]
+pub struct MetaProgressionSystem {
+    consumable_get_event_reader: Option<ReaderId<ConsumableGetEvent>>,
+}
+
+impl<'s> System<'s> for MetaProgressionSystem {
+    type SystemData = (
+        Read<'s, EventChannel<ConsumableGetEvent>>,
+        ReadExpect<'s, ConsumableModifiersResource>,
+        Read<'s, PhaseManagerResource>,
+        WriteExpect<'s, SaveProfileResource>,
+    );
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.consumable_get_event_reader = Some(
+            world
+                .fetch_mut::<EventChannel<ConsumableGetEvent>>()
+                .register_reader(),
+        );
+    }
+
+    fn run(
+        &mut self,
+        (
+            consumable_get_event_channel,
+            consumable_modifiers_resource,
+            phase_manager,
+            mut save_profile,
+        ): Self::SystemData,
+    ) {
+        for event in
+            consumable_get_event_channel.read(self.consumable_get_event_reader.as_mut().unwrap())
+        {
+            if let Some(modifiers) = consumable_modifiers_resource.get(&event.consumable_type) {
+                for modifier in modifiers.iter() {
+                    if let Modifier::Money(val) = modifier {
+                        save_profile.earn(*val);
+                    }
+                }
+            }
+        }
+
+        // save as soon as a new best phase is reached
+        if phase_manager.get_current_phase().is_some()
+            && save_profile.reach_phase(phase_manager.phase_idx)
+        {
+            save_profile.save();
+        }
+    }
+}
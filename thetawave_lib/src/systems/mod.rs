@@ -1,14 +1,25 @@
 mod ability;
 mod attraction;
+mod bomb;
 mod boss;
 mod defense;
+mod escort;
+mod meta_progression;
 mod phase_manager;
 mod play_audio;
 mod spaceship;
 mod store;
 
 pub use self::{
-    ability::BarrelRollAbilitySystem, attraction::AttractorSystem, boss::BossSystem,
-    defense::DefenseSystem, phase_manager::PhaseManagerSystem, play_audio::PlayAudioSystem,
-    spaceship::SpaceshipSystem, store::StoreSystem,
+    ability::{BarrelRollAbilitySystem, SpecialAbilitySystem},
+    attraction::AttractorSystem,
+    bomb::BombSystem,
+    boss::BossSystem,
+    defense::DefenseSystem,
+    escort::EscortSystem,
+    meta_progression::MetaProgressionSystem,
+    phase_manager::{PhaseEnemyTargetSystem, PhaseManagerSystem},
+    play_audio::PlayAudioSystem,
+    spaceship::SpaceshipSystem,
+    store::StoreSystem,
 };
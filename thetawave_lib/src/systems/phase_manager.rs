@@ -1,7 +1,12 @@
-use crate::resources::PhaseManagerResource;
+use crate::{
+    entities::MobType,
+    motion::components::{Motion2DComponent, TargetComponent},
+    resources::PhaseManagerResource,
+    spawnable::{components::MobComponent, resources::MobsResource},
+};
 use amethyst::{
     core::timing::Time,
-    ecs::prelude::{Read, System, Write},
+    ecs::prelude::{Entities, Join, Read, ReadExpect, ReadStorage, System, Write, WriteStorage},
 };
 
 pub struct PhaseManagerSystem;
@@ -13,3 +18,50 @@ impl<'s> System<'s> for PhaseManagerSystem {
         phase_manager.update(time.delta_seconds());
     }
 }
+
+/// Gives homing enemies the target preferred by the current phase, enemies with a phase target
+/// component only home in on a target during phases with a preferred target
+pub struct PhaseEnemyTargetSystem;
+
+impl<'s> System<'s> for PhaseEnemyTargetSystem {
+    type SystemData = (
+        Entities<'s>,
+        Read<'s, PhaseManagerResource>,
+        ReadExpect<'s, MobsResource>,
+        ReadStorage<'s, MobComponent>,
+        WriteStorage<'s, TargetComponent>,
+        WriteStorage<'s, Motion2DComponent>,
+    );
+
+    fn run(
+        &mut self,
+        (entities, phase_manager, mobs_resource, mobs, mut targets, mut motion_2ds): Self::SystemData,
+    ) {
+        let preferred_selector = phase_manager
+            .get_current_phase()
+            .and_then(|phase| phase.enemy_target.clone());
+
+        for (entity, mob) in (&entities, &mobs).join() {
+            if let MobType::Enemy(_) = mob.mob_type {
+                let mob_data = &mobs_resource.mob_entities[&mob.mob_type];
+
+                if preferred_selector.is_none() && mob_data.target_component.is_none() {
+                    // enemies given a target by a phase stop homing in when the phase ends
+                    if targets.remove(entity).is_some() {
+                        if let Some(motion_2d) = motion_2ds.get_mut(entity) {
+                            motion_2d.target_position = None;
+                            motion_2d.angular_velocity = 0.0;
+                        }
+                    }
+                } else if let Some(target) = targets.get_mut(entity) {
+                    target.preferred_selector = preferred_selector.clone();
+                } else if let Some(mut phase_target) = mob_data.phase_target_component.clone() {
+                    phase_target.preferred_selector = preferred_selector.clone();
+                    targets
+                        .insert(entity, phase_target)
+                        .expect("Unable to insert target component for enemy");
+                }
+            }
+        }
+    }
+}
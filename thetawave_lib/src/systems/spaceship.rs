@@ -1,16 +1,21 @@
 use crate::{
     audio::Sounds,
-    components::HealthComponent,
+    components::{HealthComponent, PlayerComponent},
+    constants::{
+        ARENA_HEIGHT, ARENA_MIN_Y, SPACESHIP_INVULNERABILITY_FLASH_RATE,
+        SPACESHIP_RESPAWN_INVULNERABILITY_DURATION,
+    },
     events::{ItemGetEvent, PlayAudioEvent},
     motion::components::Motion2DComponent,
     resources::SpriteSheetsResource,
-    weapons::components::{BlasterComponent, ManualFireComponent},
+    weapons::components::{BeamComponent, BlasterComponent, ManualFireComponent},
 };
 use amethyst::{
-    core::Transform,
+    core::{timing::Time, Hidden, Transform},
     ecs::*,
     ecs::{Entities, Join, LazyUpdate, Read, ReadExpect, System, Write, WriteStorage},
     input::{InputHandler, StringBindings},
+    renderer::{palette::Srgba, resources::Tint},
     shrev::EventChannel,
 };
 
@@ -22,12 +27,17 @@ pub struct SpaceshipSystem {
 impl<'s> System<'s> for SpaceshipSystem {
     type SystemData = (
         Entities<'s>,
+        WriteStorage<'s, PlayerComponent>,
         WriteStorage<'s, Transform>,
         WriteStorage<'s, HealthComponent>,
         WriteStorage<'s, Motion2DComponent>,
         ReadStorage<'s, BlasterComponent>,
         WriteStorage<'s, ManualFireComponent>,
+        WriteStorage<'s, BeamComponent>,
+        WriteStorage<'s, Tint>,
+        WriteStorage<'s, Hidden>,
         Read<'s, InputHandler<StringBindings>>,
+        Read<'s, Time>,
         ReadExpect<'s, SpriteSheetsResource>,
         ReadExpect<'s, LazyUpdate>,
         Write<'s, EventChannel<PlayAudioEvent>>,
@@ -47,31 +57,100 @@ impl<'s> System<'s> for SpaceshipSystem {
         &mut self,
         (
             entities,
+            mut players,
             mut transforms,
             mut healths,
             mut motion2ds,
             blasters,
             mut manual_fires,
+            mut beams,
+            mut tints,
+            mut hiddens,
             input,
+            time,
             sprite_resource,
             lazy_update,
             mut play_audio_channel,
             sounds,
         ): Self::SystemData,
     ) {
-        // collect input bools
-        let shoot_action = input.action_is_down("shoot").unwrap();
-
-        for (health, transform, motion2d, blaster, manual_fire) in (
+        for (entity, player, health, transform, motion2d, blaster, manual_fire, beam) in (
+            &*entities,
+            &mut players,
             &mut healths,
             &mut transforms,
             &mut motion2ds,
             &blasters,
             &mut manual_fires,
+            (&mut beams).maybe(),
         )
             .join()
         {
-            if shoot_action && manual_fire.ready {
+            let shoot_action = input.action_is_down(&player.action("shoot")).unwrap();
+
+            // beams fire continuously while shoot is held instead of the blaster
+            if let Some(beam) = beam {
+                beam.active = shoot_action;
+            } else if let Some(charge_time) = manual_fire.charge_time {
+                // charge while shoot is held and fire a charged blast on release
+                if shoot_action {
+                    if manual_fire.charge_timer == 0.0 {
+                        // keep the ship's tint to restore once the shot is released
+                        manual_fire.tint_before_charge = tints.get(entity).cloned();
+                    }
+                    let was_charged = manual_fire.charge_timer >= charge_time;
+                    manual_fire.charge_timer =
+                        (manual_fire.charge_timer + time.delta_seconds()).min(charge_time);
+
+                    // cue the player once the shot is fully charged
+                    if !was_charged && manual_fire.charge_timer >= charge_time {
+                        play_audio_channel.single_write(PlayAudioEvent {
+                            source: sounds.sound_effects["charge_ready"].clone(),
+                        });
+                    }
+
+                    let charge_level = manual_fire.charge_level();
+                    let base_color = manual_fire
+                        .tint_before_charge
+                        .map_or(Srgba::new(1.0, 1.0, 1.0, 1.0), |tint| tint.0);
+                    tints
+                        .insert(
+                            entity,
+                            Tint(Srgba::new(
+                                base_color.red * (1.0 - 0.5 * charge_level),
+                                base_color.green * (1.0 - 0.2 * charge_level),
+                                base_color.blue,
+                                base_color.alpha,
+                            )),
+                        )
+                        .expect("unable to insert tint component");
+                } else if manual_fire.charge_timer > 0.0 {
+                    if manual_fire.ready {
+                        manual_fire.charged_blaster(blaster).fire(
+                            motion2d,
+                            transform,
+                            &entities,
+                            &sprite_resource,
+                            &lazy_update,
+                        );
+                        manual_fire.ready = false;
+                        play_audio_channel.single_write(PlayAudioEvent {
+                            source: sounds.sound_effects["laser_blast"].clone(),
+                        });
+                    }
+
+                    manual_fire.charge_timer = 0.0;
+
+                    // restore the tint the ship had before charging
+                    if let Some(tint) = manual_fire.tint_before_charge.take() {
+                        tints
+                            .insert(entity, tint)
+                            .expect("unable to insert tint component");
+                    } else {
+                        tints.remove(entity);
+                    }
+                }
+            } else if shoot_action && manual_fire.ready {
                 blaster.fire(
                     motion2d,
                     transform,
@@ -85,7 +164,40 @@ impl<'s> System<'s> for SpaceshipSystem {
                 });
             }
 
+            health.update_invulnerability(time.delta_seconds());
+
+            if health.value <= 0.0 {
+                play_audio_channel.single_write(PlayAudioEvent {
+                    source: sounds.sound_effects["explosion"].clone(),
+                });
+
+                if player.lives > 0 {
+                    // respawn at the bottom of the arena with full health
+                    player.lives -= 1;
+                    health.value = health.max_value;
+                    health.make_invulnerable(SPACESHIP_RESPAWN_INVULNERABILITY_DURATION);
+                    motion2d.velocity.x = 0.0;
+                    motion2d.velocity.y = 0.0;
+                    transform.set_translation_y(ARENA_MIN_Y + (ARENA_HEIGHT / 6.0));
+                } else {
+                    entities.delete(entity).expect("unable to delete entity");
+                }
+            }
+
             health.constrain();
+
+            // flash the sprite while invulnerable
+            if health.invulnerability_timer > 0.0
+                && (health.invulnerability_timer * SPACESHIP_INVULNERABILITY_FLASH_RATE) as usize
+                    % 2
+                    == 0
+            {
+                hiddens
+                    .insert(entity, Hidden)
+                    .expect("unable to insert hidden component");
+            } else {
+                hiddens.remove(entity);
+            }
         }
     }
 }
@@ -1,14 +1,16 @@
 use crate::{
     audio::Sounds,
     components::PlayerComponent,
+    constants::{STORE_ICONS_BOTTOM_Y, STORE_ICONS_TOP_Y},
     entities::SpawnableType,
-    events::PlayAudioEvent,
-    resources::{SpriteSheetsResource, StoreResource},
+    events::{ItemRemoveEvent, PlayAudioEvent},
+    resources::{PhaseManagerResource, PhaseType, SpriteSheetsResource, StoreResource},
     spawnable::resources::{ConsumablesResource, ItemsResource},
+    tools::PressTracker,
     visual::components::StoreIconComponent,
 };
 use amethyst::{
-    core::{timing::Time, Transform},
+    core::{timing::Time, Hidden, Transform},
     ecs::{
         Entities, Join, LazyUpdate, Read, ReadExpect, ReadStorage, System, Write, WriteExpect,
         WriteStorage,
@@ -18,7 +20,23 @@ use amethyst::{
     shrev::EventChannel,
 };
 
-pub struct StoreSystem;
+#[derive(Default)]
+pub struct StoreSystem {
+    /// Tracks presses of each player's sell action
+    sell_presses: PressTracker,
+    /// Tracks presses of each player's select item action
+    select_item_presses: PressTracker,
+    /// Tracks presses of each player's inspect action
+    inspect_presses: PressTracker,
+    /// Tracks presses of each player's reroll action
+    reroll_presses: PressTracker,
+    /// Tracks presses of each player's lock action
+    lock_presses: PressTracker,
+    /// Inventory shown by the store icons
+    displayed_inventory: Vec<Option<SpawnableType>>,
+    /// Whether the store icons show an open store
+    displayed_open: bool,
+}
 
 impl<'s> System<'s> for StoreSystem {
     type SystemData = (
@@ -31,11 +49,14 @@ impl<'s> System<'s> for StoreSystem {
         Read<'s, Time>,
         Read<'s, InputHandler<StringBindings>>,
         WriteStorage<'s, PlayerComponent>,
-        ReadStorage<'s, Transform>,
+        WriteStorage<'s, Transform>,
         Write<'s, EventChannel<PlayAudioEvent>>,
         ReadExpect<'s, Sounds>,
         ReadStorage<'s, StoreIconComponent>,
         WriteStorage<'s, SpriteRender>,
+        Write<'s, EventChannel<ItemRemoveEvent>>,
+        WriteStorage<'s, Hidden>,
+        Read<'s, PhaseManagerResource>,
     );
 
     fn run(
@@ -50,78 +71,106 @@ impl<'s> System<'s> for StoreSystem {
             time,
             input,
             mut players,
-            transforms,
+            mut transforms,
             mut play_audio_channel,
             sounds,
             store_icons,
             mut sprite_renders,
+            mut item_remove_event_channel,
+            mut hiddens,
+            phase_manager,
         ): Self::SystemData,
     ) {
-        let buy_0_action = input.action_is_down("buy_0").unwrap();
-        let buy_1_action = input.action_is_down("buy_1").unwrap();
-        let buy_2_action = input.action_is_down("buy_2").unwrap();
-
-        if store_resource.restock_when_ready(time.delta_seconds()) {
-            // change store icons
-            for (store_icon, sprite_render) in (&store_icons, &mut sprite_renders).join() {
-                if let Some(SpawnableType::Item(item_type)) =
-                    store_resource.inventory[store_icon.inventory_index].clone()
-                {
-                    let item_data = items_resource.item_entities[&item_type].clone();
-                    *sprite_render = SpriteRender {
-                        sprite_sheet: spritesheets_resource.spritesheets
-                            [&item_data.sprite_render_data.spritesheet]
-                            .clone(),
-                        sprite_number: item_data.sprite_render_data.initial_index,
-                    }
-                } else if let Some(SpawnableType::Consumable(consumable_type)) =
-                    store_resource.inventory[store_icon.inventory_index].clone()
-                {
-                    let consumable_data =
-                        consumables_resource.consumable_entities[&consumable_type].clone();
-                    *sprite_render = SpriteRender {
-                        sprite_sheet: spritesheets_resource.spritesheets
-                            [&consumable_data.sprite_render_data.spritesheet]
-                            .clone(),
-                        sprite_number: consumable_data.sprite_render_data.initial_index,
-                    }
-                } else {
-                    // if no inventory in slot put blank sprite
-                    *sprite_render = SpriteRender {
-                        sprite_sheet: spritesheets_resource.spritesheets["items"].clone(),
-                        sprite_number: 0,
-                    }
+        store_resource.update(
+            time.delta_seconds(),
+            phase_manager.phase_idx,
+            matches!(
+                phase_manager.get_current_phase_type(),
+                Some(PhaseType::Rest)
+            ),
+            &items_resource,
+        );
+        let is_open = store_resource.is_open();
+
+        // TODO: streamline purchase_item function with constant component in item data file
+        for (entity, character, transform) in (&*entities, &mut players, &transforms).join() {
+            // a closed store ignores the store actions of every player
+            if !is_open {
+                break;
+            }
+
+            // select the next held item for selling once per press
+            let select_item_action = input
+                .action_is_down(&character.action("select_item"))
+                .unwrap();
+            let select_item_pressed = self
+                .select_item_presses
+                .pressed(character.player_index, select_item_action);
+
+            if select_item_pressed {
+                character.select_next_item();
+            }
+
+            // sell the selected item once per press
+            let sell_action = input.action_is_down(&character.action("sell")).unwrap();
+            let sell_pressed = self
+                .sell_presses
+                .pressed(character.player_index, sell_action);
+
+            if sell_pressed {
+                if let Some(item_type) = store_resource.sell(character, &items_resource) {
+                    item_remove_event_channel.single_write(ItemRemoveEvent {
+                        player_entity: entity,
+                        item_type,
+                    });
+                    play_audio_channel.single_write(PlayAudioEvent {
+                        source: sounds.sound_effects["cash_register_bell"].clone(),
+                    });
                 }
             }
-        }
 
-        // TODO: streamline purchase_item function with constant component in item data file
-        for (character, transform) in (&mut players, &transforms).join() {
-            if (buy_0_action
-                && store_resource.purchase(
-                    0,
-                    &entities,
-                    character,
-                    transform,
-                    &items_resource,
-                    &consumables_resource,
-                    &spritesheets_resource,
-                    &lazy_update,
-                ))
-                || (buy_1_action
-                    && store_resource.purchase(
-                        1,
-                        &entities,
-                        character,
-                        transform,
-                        &items_resource,
-                        &consumables_resource,
-                        &spritesheets_resource,
-                        &lazy_update,
-                    ))
-                || (buy_2_action
+            // highlight the next slot in the store details once per press
+            let inspect_action = input.action_is_down(&character.action("inspect")).unwrap();
+            let inspect_pressed = self
+                .inspect_presses
+                .pressed(character.player_index, inspect_action);
+
+            if inspect_pressed {
+                store_resource.inspect_next(entity, character.player_index);
+            }
+
+            // restock the unlocked slots once per press
+            let reroll_action = input.action_is_down(&character.action("reroll")).unwrap();
+            let reroll_pressed = self
+                .reroll_presses
+                .pressed(character.player_index, reroll_action);
+
+            if reroll_pressed && store_resource.reroll(character, &items_resource) {
+                play_audio_channel.single_write(PlayAudioEvent {
+                    source: sounds.sound_effects["cash_register_bell"].clone(),
+                });
+            }
+
+            // lock or unlock the highlighted slot once per press
+            let lock_action = input.action_is_down(&character.action("lock")).unwrap();
+            let lock_pressed = self
+                .lock_presses
+                .pressed(character.player_index, lock_action);
+
+            if lock_pressed {
+                store_resource.toggle_lock(character.player_index);
+            }
+
+            // each player buys from the store with their own bindings,
+            // slots without a binding can't be bought
+            for inventory_index in 0..store_resource.inventory.len() {
+                let buy_action = input
+                    .action_is_down(&character.action(&format!("buy_{}", inventory_index)))
+                    .unwrap_or(false);
+
+                if buy_action
                     && store_resource.purchase(
-                        2,
+                        inventory_index,
                         &entities,
                         character,
                         transform,
@@ -129,33 +178,98 @@ impl<'s> System<'s> for StoreSystem {
                         &consumables_resource,
                         &spritesheets_resource,
                         &lazy_update,
-                    ))
+                    )
+                {
+                    play_audio_channel.single_write(PlayAudioEvent {
+                        source: sounds.sound_effects["cash_register_bell"].clone(),
+                    });
+                }
+            }
+        }
+
+        // change store icons when the inventory changes, the store opens or closes, or another
+        // store system changed the icons
+        if self.displayed_inventory != store_resource.inventory
+            || self.displayed_open != is_open
+            || store_resource.icons_outdated
+        {
+            store_resource.icons_outdated = false;
+            self.displayed_inventory = store_resource.inventory.clone();
+            self.displayed_open = is_open;
+
+            for (entity, store_icon, sprite_render, transform) in (
+                &entities,
+                &store_icons,
+                &mut sprite_renders,
+                &mut transforms,
+            )
+                .join()
             {
-                play_audio_channel.single_write(PlayAudioEvent {
-                    source: sounds.sound_effects["cash_register_bell"].clone(),
-                });
+                let inventory_entity =
+                    match store_resource.inventory.get(store_icon.inventory_index) {
+                        Some(inventory_entity) if is_open => inventory_entity,
+                        _ => {
+                            // slots the store doesn't have and slots of a closed store are hidden
+                            hiddens
+                                .insert(entity, Hidden)
+                                .expect("Unable to insert hidden component for store icon");
+                            continue;
+                        }
+                    };
 
-                // change store icons
-                for (store_icon, sprite_render) in (&store_icons, &mut sprite_renders).join() {
-                    if buy_0_action && store_icon.inventory_index == 0 {
-                        *sprite_render = SpriteRender {
-                            sprite_sheet: spritesheets_resource.spritesheets["items"].clone(),
-                            sprite_number: 0,
+                // items are framed by their rarity tier, other slots have no frame
+                let icon_sprite_render = if store_icon.is_frame {
+                    match inventory_entity {
+                        Some(SpawnableType::Item(item_type)) => {
+                            items_resource.frame_sprite_render(item_type, &spritesheets_resource)
                         }
+                        _ => None,
                     }
-                    if buy_1_action && store_icon.inventory_index == 1 {
-                        *sprite_render = SpriteRender {
-                            sprite_sheet: spritesheets_resource.spritesheets["items"].clone(),
-                            sprite_number: 0,
+                } else {
+                    Some(match inventory_entity {
+                        Some(SpawnableType::Item(item_type)) => {
+                            let item_data = &items_resource.item_entities[item_type];
+                            SpriteRender {
+                                sprite_sheet: spritesheets_resource.spritesheets
+                                    [&item_data.sprite_render_data.spritesheet]
+                                    .clone(),
+                                sprite_number: item_data.sprite_render_data.initial_index,
+                            }
                         }
-                    }
-                    if buy_2_action && store_icon.inventory_index == 2 {
-                        *sprite_render = SpriteRender {
+                        Some(SpawnableType::Consumable(consumable_type)) => {
+                            let consumable_data =
+                                &consumables_resource.consumable_entities[consumable_type];
+                            SpriteRender {
+                                sprite_sheet: spritesheets_resource.spritesheets
+                                    [&consumable_data.sprite_render_data.spritesheet]
+                                    .clone(),
+                                sprite_number: consumable_data.sprite_render_data.initial_index,
+                            }
+                        }
+                        // if no inventory in slot put blank sprite
+                        _ => SpriteRender {
                             sprite_sheet: spritesheets_resource.spritesheets["items"].clone(),
                             sprite_number: 0,
-                        }
+                        },
+                    })
+                };
+
+                match icon_sprite_render {
+                    Some(icon_sprite_render) => *sprite_render = icon_sprite_render,
+                    None => {
+                        hiddens
+                            .insert(entity, Hidden)
+                            .expect("Unable to insert hidden component for store icon");
+                        continue;
                     }
                 }
+
+                transform.set_translation_y(
+                    STORE_ICONS_TOP_Y
+                        - (STORE_ICONS_TOP_Y - STORE_ICONS_BOTTOM_Y)
+                            * store_resource.slot_position(store_icon.inventory_index),
+                );
+                hiddens.remove(entity);
             }
         }
     }
@@ -1,4 +1,5 @@
 mod math;
+mod press;
 mod random;
 
 pub use self::{
@@ -6,5 +7,6 @@ pub use self::{
         distance, dot_product, edge_vector, orthogonal, overlap, poly_to_edges, project, rotate_x,
         rotate_y, run_sat, sat_is_colliding, signed_modulo, Vector,
     },
+    press::PressTracker,
     random::weighted_rng,
 };
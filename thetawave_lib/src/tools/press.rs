@@ -0,0 +1,15 @@
+use std::collections::HashMap;
+
+/// Tracks whether an action of each player was down in the previous frame
+#[derive(Default)]
+pub struct PressTracker {
+    was_down: HashMap<usize, bool>,
+}
+
+impl PressTracker {
+    /// Record the action state of a player, returns true only on the frame the action is pressed
+    pub fn pressed(&mut self, player_index: usize, is_down: bool) -> bool {
+        let was_down = self.was_down.insert(player_index, is_down).unwrap_or(false);
+        is_down && !was_down
+    }
+}
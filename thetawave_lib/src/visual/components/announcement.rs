@@ -0,0 +1,12 @@
+use amethyst::ecs::prelude::{Component, DenseVecStorage};
+
+/// Used for managing the text that shows announcements
+#[derive(Clone, Default)]
+pub struct AnnouncementComponent {
+    /// Time in seconds until the current announcement is cleared
+    pub timer: f32,
+}
+
+impl Component for AnnouncementComponent {
+    type Storage = DenseVecStorage<Self>;
+}
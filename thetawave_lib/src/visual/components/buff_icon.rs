@@ -0,0 +1,14 @@
+use amethyst::ecs::prelude::{Component, DenseVecStorage, Entity};
+
+/// Used for managing buff icons
+#[derive(Clone)]
+pub struct BuffIconComponent {
+    /// Index of the active buff represented
+    pub slot_index: usize,
+    /// Text entity showing the remaining time of the buff
+    pub timer_text: Entity,
+}
+
+impl Component for BuffIconComponent {
+    type Storage = DenseVecStorage<Self>;
+}
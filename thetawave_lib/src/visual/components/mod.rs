@@ -1,12 +1,16 @@
 //! Components for managing visuals
 
 mod animation;
+mod announcement;
+mod buff_icon;
 mod fade;
 mod status_bar;
 mod store_icon;
 
 pub use self::{
     animation::{AnimationComponent, AnimationType},
+    announcement::AnnouncementComponent,
+    buff_icon::BuffIconComponent,
     fade::{ColorChannelChange, FadeComponent},
     status_bar::{StatusBarComponent, StatusType},
     store_icon::StoreIconComponent,
@@ -7,9 +7,11 @@ use std::{cmp::Ordering, vec::Vec};
 
 /// Type of status bar
 pub enum StatusType {
-    Health,
+    /// Health of the player with the given index
+    Health(usize),
     Defense,
-    Roll,
+    /// Ability cooldown of the player with the given index
+    Roll(usize),
     Restock,
 }
 
@@ -5,6 +5,8 @@ use amethyst::ecs::prelude::{Component, DenseVecStorage};
 pub struct StoreIconComponent {
     /// Index of the store slot represented
     pub inventory_index: usize,
+    /// Whether the icon shows the rarity frame of the slot's item instead of the item
+    pub is_frame: bool,
 }
 
 impl Component for StoreIconComponent {
@@ -0,0 +1,40 @@
+use crate::visual::components::AnnouncementComponent;
+use amethyst::{
+    assets::Loader,
+    ecs::{World, WorldExt},
+    prelude::Builder,
+    ui::{Anchor, LineMode, TtfFormat, UiText, UiTransform},
+};
+
+/// Initialize the empty announcement text at the top of the arena
+pub fn initialize_announcement(world: &mut World) {
+    let font = world.read_resource::<Loader>().load(
+        "font/SpaceMadness.ttf",
+        TtfFormat,
+        (),
+        &world.read_resource(),
+    );
+
+    world
+        .create_entity()
+        .with(AnnouncementComponent::default())
+        .with(UiTransform::new(
+            "announcement".to_string(),
+            Anchor::TopMiddle,
+            Anchor::TopMiddle,
+            0.0,
+            -40.0,
+            0.9,
+            500.0,
+            45.0,
+        ))
+        .with(UiText::new(
+            font,
+            "".to_string(),
+            [1.0, 1.0, 1.0, 1.0],
+            20.0,
+            LineMode::Single,
+            Anchor::Middle,
+        ))
+        .build();
+}
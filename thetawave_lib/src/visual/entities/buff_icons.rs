@@ -0,0 +1,67 @@
+use crate::{
+    constants::{
+        BUFF_ICON_SLOTS, BUFF_ICON_SPACING, BUFF_ICON_X, BUFF_ICON_Y, SIDE_PANEL_TEXT_ORIGIN_Y,
+    },
+    visual::components::BuffIconComponent,
+};
+use amethyst::{
+    assets::{Handle, Loader},
+    core::{transform::Transform, Hidden},
+    ecs::{World, WorldExt},
+    prelude::Builder,
+    renderer::{SpriteRender, SpriteSheet},
+    ui::{Anchor, LineMode, TtfFormat, UiText, UiTransform},
+};
+
+/// Initialize the hidden buff icons and timers on the left side panel
+pub fn initialize_buff_icons(world: &mut World, sprite_sheet_handle: Handle<SpriteSheet>) {
+    let font = world.read_resource::<Loader>().load(
+        "font/SpaceMadness.ttf",
+        TtfFormat,
+        (),
+        &world.read_resource(),
+    );
+
+    for slot_index in 0..BUFF_ICON_SLOTS {
+        let icon_y = BUFF_ICON_Y - (slot_index as f32 * BUFF_ICON_SPACING);
+
+        let timer_text = world
+            .create_entity()
+            .with(UiTransform::new(
+                format!("buff_timer_{}", slot_index),
+                Anchor::MiddleLeft,
+                Anchor::MiddleLeft,
+                6.0,
+                icon_y - SIDE_PANEL_TEXT_ORIGIN_Y,
+                0.9,
+                50.0,
+                45.0,
+            ))
+            .with(UiText::new(
+                font.clone(),
+                "".to_string(),
+                [1.0, 1.0, 1.0, 1.0],
+                15.0,
+                LineMode::Single,
+                Anchor::Middle,
+            ))
+            .build();
+
+        let mut transform = Transform::default();
+        transform.set_translation_xyz(BUFF_ICON_X, icon_y, 0.9);
+
+        world
+            .create_entity()
+            .with(BuffIconComponent {
+                slot_index,
+                timer_text,
+            })
+            .with(transform)
+            .with(SpriteRender {
+                sprite_sheet: sprite_sheet_handle.clone(),
+                sprite_number: 0, // index of the blank item icon on the spritesheet
+            })
+            .with(Hidden)
+            .build();
+    }
+}
@@ -1,6 +1,8 @@
 //! Entities for managing visuals
 
+mod announcement;
 mod backgrounds;
+mod buff_icons;
 mod planet;
 mod side_panels;
 mod status_bar;
@@ -8,7 +10,8 @@ mod status_unit;
 mod store_icons;
 
 pub use self::{
-    backgrounds::initialize_background, planet::initialize_planet,
+    announcement::initialize_announcement, backgrounds::initialize_background,
+    buff_icons::initialize_buff_icons, planet::initialize_planet,
     side_panels::initialize_side_panels, status_bar::initialize_status_bars,
     status_unit::spawn_status_unit, store_icons::initialize_store_icons,
 };
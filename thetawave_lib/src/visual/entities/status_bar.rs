@@ -1,8 +1,8 @@
 use crate::{
     constants::{
         DEFENSE_BAR_LIMIT, DEFENSE_BAR_X, DEFENSE_BAR_Y, HEALTH_BAR_LIMIT, HEALTH_BAR_X,
-        HEALTH_BAR_Y, RESTOCK_BAR_LIMIT, RESTOCK_BAR_X, RESTOCK_BAR_Y, ROLL_BAR_LIMIT, ROLL_BAR_X,
-        ROLL_BAR_Y,
+        HEALTH_BAR_Y, P2_HEALTH_BAR_X, P2_ROLL_BAR_X, RESTOCK_BAR_LIMIT, RESTOCK_BAR_X,
+        RESTOCK_BAR_Y, ROLL_BAR_LIMIT, ROLL_BAR_X, ROLL_BAR_Y,
     },
     visual::components::{StatusBarComponent, StatusType},
 };
@@ -11,13 +11,14 @@ use amethyst::{
     prelude::Builder,
 };
 
-/// Initialize the health, defense, roll, and store status bars
+/// Initialize the health, defense, roll, and store status bars,
+/// player two's health and roll bars are on the left side panel
 pub fn initialize_status_bars(world: &mut World) {
     //health bar
     world
         .create_entity()
         .with(StatusBarComponent {
-            status_type: StatusType::Health,
+            status_type: StatusType::Health(0),
             x_pos: HEALTH_BAR_X,
             y_pos: HEALTH_BAR_Y,
             status_unit_stack: vec![],
@@ -41,7 +42,7 @@ pub fn initialize_status_bars(world: &mut World) {
     world
         .create_entity()
         .with(StatusBarComponent {
-            status_type: StatusType::Roll,
+            status_type: StatusType::Roll(0),
             x_pos: ROLL_BAR_X,
             y_pos: ROLL_BAR_Y,
             status_unit_stack: vec![],
@@ -49,6 +50,30 @@ pub fn initialize_status_bars(world: &mut World) {
         })
         .build();
 
+    //player two health bar
+    world
+        .create_entity()
+        .with(StatusBarComponent {
+            status_type: StatusType::Health(1),
+            x_pos: P2_HEALTH_BAR_X,
+            y_pos: HEALTH_BAR_Y,
+            status_unit_stack: vec![],
+            unit_limit: HEALTH_BAR_LIMIT,
+        })
+        .build();
+
+    //player two roll bar
+    world
+        .create_entity()
+        .with(StatusBarComponent {
+            status_type: StatusType::Roll(1),
+            x_pos: P2_ROLL_BAR_X,
+            y_pos: ROLL_BAR_Y,
+            status_unit_stack: vec![],
+            unit_limit: ROLL_BAR_LIMIT,
+        })
+        .build();
+
     //restock bar
     world
         .create_entity()
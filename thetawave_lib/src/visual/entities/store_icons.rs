@@ -1,53 +1,57 @@
 use crate::{
-    constants::{ARENA_MAX_X, ARENA_MIN_Y},
+    constants::{STORE_ICONS_TOP_Y, STORE_ICON_X},
+    resources::StoreResource,
     visual::components::StoreIconComponent,
 };
 use amethyst::{
     assets::Handle,
-    core::transform::Transform,
+    core::{transform::Transform, Hidden},
     ecs::{World, WorldExt},
     prelude::Builder,
     renderer::{SpriteRender, SpriteSheet},
 };
 
-/// Initialize the store icons
+/// Initialize an icon and a rarity frame for each slot the store can have, icons are positioned
+/// and shown by the store system
 pub fn initialize_store_icons(world: &mut World, sprite_sheet_handle: Handle<SpriteSheet>) {
-    let store_icon_1 = StoreIconComponent { inventory_index: 0 };
-    let store_icon_2 = StoreIconComponent { inventory_index: 1 };
-    let store_icon_3 = StoreIconComponent { inventory_index: 2 };
+    let max_slot_count = {
+        let store_resource = world.read_resource::<StoreResource>();
+        store_resource.max_slot_count.max(store_resource.slot_count)
+    };
 
     let sprite_render = SpriteRender {
         sprite_sheet: sprite_sheet_handle,
         sprite_number: 0, // index of the blank item icon on the spritesheet
     };
 
-    let mut transform_1 = Transform::default();
-    transform_1.set_translation_xyz(ARENA_MAX_X + 12.0, ARENA_MIN_Y + 72.0, 0.9);
-
-    let mut transform_2 = transform_1.clone();
-    transform_2.set_translation_y(ARENA_MIN_Y + 53.0);
-
-    let mut transform_3 = transform_1.clone();
-    transform_3.set_translation_y(ARENA_MIN_Y + 34.0);
-
-    world
-        .create_entity()
-        .with(store_icon_1)
-        .with(transform_1)
-        .with(sprite_render.clone())
-        .build();
-
-    world
-        .create_entity()
-        .with(store_icon_2)
-        .with(transform_2)
-        .with(sprite_render.clone())
-        .build();
-
-    world
-        .create_entity()
-        .with(store_icon_3)
-        .with(transform_3)
-        .with(sprite_render)
-        .build();
+    let mut transform = Transform::default();
+    transform.set_translation_xyz(STORE_ICON_X, STORE_ICONS_TOP_Y, 0.9);
+
+    // frames are drawn over the icons
+    let mut frame_transform = transform.clone();
+    frame_transform.set_translation_z(0.95);
+
+    for inventory_index in 0..max_slot_count {
+        world
+            .create_entity()
+            .with(StoreIconComponent {
+                inventory_index,
+                is_frame: false,
+            })
+            .with(transform.clone())
+            .with(sprite_render.clone())
+            .with(Hidden)
+            .build();
+
+        world
+            .create_entity()
+            .with(StoreIconComponent {
+                inventory_index,
+                is_frame: true,
+            })
+            .with(frame_transform.clone())
+            .with(sprite_render.clone())
+            .with(Hidden)
+            .build();
+    }
 }
@@ -0,0 +1,63 @@
+use crate::{
+    constants::ANNOUNCEMENT_DURATION, events::AnnouncementEvent,
+    visual::components::AnnouncementComponent,
+};
+use amethyst::{
+    core::timing::Time,
+    ecs::*,
+    ecs::{Read, System, World, WriteStorage},
+    shrev::{EventChannel, ReaderId},
+    ui::UiText,
+};
+
+/// Shows announcements on screen and clears them after a delay
+#[derive(Default)]
+pub struct AnnouncementSystem {
+    /// Reads from the announcement event channel
+    event_reader: Option<ReaderId<AnnouncementEvent>>,
+}
+
+impl<'s> System<'s> for AnnouncementSystem {
+    /// Data used by the system
+    type SystemData = (
+        Read<'s, EventChannel<AnnouncementEvent>>,
+        WriteStorage<'s, AnnouncementComponent>,
+        WriteStorage<'s, UiText>,
+        Read<'s, Time>,
+    );
+
+    /// Sets up event readers
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.event_reader = Some(
+            world
+                .fetch_mut::<EventChannel<AnnouncementEvent>>()
+                .register_reader(),
+        );
+    }
+
+    /// System game logic
+    fn run(
+        &mut self,
+        (announcement_event_channel, mut announcements, mut ui_texts, time): Self::SystemData,
+    ) {
+        // the most recent announcement replaces older ones
+        let latest_text = announcement_event_channel
+            .read(self.event_reader.as_mut().unwrap())
+            .last()
+            .map(|event| event.text.clone());
+
+        for (announcement, ui_text) in (&mut announcements, &mut ui_texts).join() {
+            if let Some(text) = latest_text.clone() {
+                ui_text.text = text;
+                announcement.timer = ANNOUNCEMENT_DURATION;
+            } else if announcement.timer > 0.0 {
+                announcement.timer -= time.delta_seconds();
+
+                if announcement.timer <= 0.0 {
+                    ui_text.text = "".to_string();
+                }
+            }
+        }
+    }
+}
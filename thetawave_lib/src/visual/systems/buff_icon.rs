@@ -0,0 +1,92 @@
+use crate::{
+    components::{ActiveBuff, PlayerComponent, StatSheetComponent},
+    resources::SpriteSheetsResource,
+    spawnable::resources::BuffsResource,
+    visual::components::BuffIconComponent,
+};
+use amethyst::{
+    core::Hidden,
+    ecs::prelude::{Entities, Join, ReadExpect, ReadStorage, System, WriteStorage},
+    renderer::SpriteRender,
+    ui::UiText,
+};
+
+/// Shows the icons and remaining times of active buffs on the side panel
+pub struct BuffIconSystem;
+
+impl<'s> System<'s> for BuffIconSystem {
+    /// Data used by the system
+    type SystemData = (
+        Entities<'s>,
+        ReadStorage<'s, PlayerComponent>,
+        ReadStorage<'s, StatSheetComponent>,
+        ReadStorage<'s, BuffIconComponent>,
+        WriteStorage<'s, SpriteRender>,
+        WriteStorage<'s, Hidden>,
+        WriteStorage<'s, UiText>,
+        ReadExpect<'s, BuffsResource>,
+        ReadExpect<'s, SpriteSheetsResource>,
+    );
+
+    /// System game logic
+    fn run(
+        &mut self,
+        (
+            entities,
+            players,
+            stat_sheets,
+            buff_icons,
+            mut sprite_renders,
+            mut hiddens,
+            mut ui_text,
+            buffs_resource,
+            spritesheets_resource,
+        ): Self::SystemData,
+    ) {
+        // buffs of each player are listed in player order
+        let mut active_buffs: Vec<(usize, &ActiveBuff)> = (&players, &stat_sheets)
+            .join()
+            .flat_map(|(player, stat_sheet)| {
+                stat_sheet
+                    .buffs
+                    .iter()
+                    .map(move |active_buff| (player.player_index, active_buff))
+            })
+            .collect();
+        active_buffs.sort_by_key(|(player_index, _)| *player_index);
+        let player_count = players.join().count();
+
+        for (entity, buff_icon, sprite_render) in
+            (&entities, &buff_icons, &mut sprite_renders).join()
+        {
+            let timer_text = if let Some((player_index, active_buff)) =
+                active_buffs.get(buff_icon.slot_index)
+            {
+                let sprite_render_data = &buffs_resource[&active_buff.buff_type].sprite_render_data;
+                *sprite_render = SpriteRender {
+                    sprite_sheet: spritesheets_resource.spritesheets
+                        [&sprite_render_data.spritesheet]
+                        .clone(),
+                    sprite_number: sprite_render_data.initial_index,
+                };
+                hiddens.remove(entity);
+
+                if player_count > 1 {
+                    format!("p{} {:.0}", player_index + 1, active_buff.timer.ceil())
+                } else {
+                    format!("{:.0}", active_buff.timer.ceil())
+                }
+            } else {
+                hiddens
+                    .insert(entity, Hidden)
+                    .expect("unable to insert hidden component");
+
+                "".to_string()
+            };
+
+            if let Some(text) = ui_text.get_mut(buff_icon.timer_text) {
+                text.text = timer_text;
+            }
+        }
+    }
+}
@@ -1,6 +1,8 @@
 //! Systems for managing visuals
 
 mod animation;
+mod announcement;
+mod buff_icon;
 mod fade;
 mod planets;
 mod stat_tracker;
@@ -8,6 +10,8 @@ mod status_bar;
 
 pub use self::{
     animation::AnimationSystem,
+    announcement::AnnouncementSystem,
+    buff_icon::BuffIconSystem,
     fade::FadeSystem,
     planets::PlanetsSystem,
     stat_tracker::{StatTrackerSystem, TrackedStats},
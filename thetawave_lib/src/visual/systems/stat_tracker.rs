@@ -1,12 +1,18 @@
 use crate::{
-    components::{HealthComponent, PlayerComponent},
+    components::{player_action, HealthComponent, PlayerComponent, StatSheetComponent},
+    constants::{ITEM_PRICES_BOTTOM_Y, ITEM_PRICES_TOP_Y},
     entities::SpawnableType,
     resources::StoreResource,
-    spawnable::resources::{ConsumablesResource, ItemsResource},
+    spawnable::resources::{
+        BuffsResource, ConsumableModifiersResource, ConsumablesResource, ItemModifiersResource,
+        ItemsResource, StatClampsResource,
+    },
+    weapons::components::SecondaryWeaponComponent,
 };
 use amethyst::{
-    ecs::prelude::{Entity, Join, ReadExpect, ReadStorage, System, WriteStorage},
-    ui::UiText,
+    ecs::prelude::{Entity, Join, Read, ReadExpect, ReadStorage, System, WriteStorage},
+    input::{Button, InputHandler, StringBindings},
+    ui::{UiText, UiTransform},
 };
 
 /// Holds variable values that are displayed
@@ -14,9 +20,12 @@ use amethyst::{
 pub struct TrackedStats {
     pub currency: Entity,
     pub shields: Entity,
-    pub item_price_1: Entity,
-    pub item_price_2: Entity,
-    pub item_price_3: Entity,
+    pub bombs: Entity,
+    pub lives: Entity,
+    pub secondary_weapon: Entity,
+    /// Price of each slot the store can have
+    pub item_prices: Vec<Entity>,
+    pub store_details: Entity,
 }
 
 /// Handles and displays tracked stats
@@ -27,11 +36,19 @@ impl<'s> System<'s> for StatTrackerSystem {
     type SystemData = (
         ReadStorage<'s, PlayerComponent>,
         ReadStorage<'s, HealthComponent>,
+        ReadStorage<'s, SecondaryWeaponComponent>,
         ReadExpect<'s, StoreResource>,
         WriteStorage<'s, UiText>,
         ReadExpect<'s, TrackedStats>,
         ReadExpect<'s, ItemsResource>,
         ReadExpect<'s, ConsumablesResource>,
+        ReadStorage<'s, StatSheetComponent>,
+        ReadExpect<'s, ItemModifiersResource>,
+        ReadExpect<'s, ConsumableModifiersResource>,
+        ReadExpect<'s, StatClampsResource>,
+        WriteStorage<'s, UiTransform>,
+        ReadExpect<'s, BuffsResource>,
+        Read<'s, InputHandler<StringBindings>>,
     );
 
     /// System game logic
@@ -40,60 +57,268 @@ impl<'s> System<'s> for StatTrackerSystem {
         (
             players,
             healths,
+            secondary_weapons,
             store_resource,
             mut ui_text,
             tracked_stats,
             items_resource,
             consumables_resource,
+            stat_sheets,
+            item_modifiers_resource,
+            consumable_modifiers_resource,
+            stat_clamps_resource,
+            mut ui_transforms,
+            buffs_resource,
+            input,
         ): Self::SystemData,
     ) {
-        for character in (&players).join() {
-            if let Some(text) = ui_text.get_mut(tracked_stats.currency) {
-                text.text = format!("x{}", character.money.to_string());
-            }
+        // stats of each player are shown side by side in player order
+        let mut player_stats: Vec<(usize, usize, usize, usize, String, usize)> =
+            (&players, &healths, &secondary_weapons)
+                .join()
+                .map(|(character, health, secondary_weapon)| {
+                    (
+                        character.player_index,
+                        character.money,
+                        character.bombs,
+                        health.armor,
+                        match secondary_weapon.equipped_weapon() {
+                            Some(weapon) => {
+                                format!("{} x{}", weapon.weapon_type.label(), weapon.ammo)
+                            }
+                            None => "".to_string(),
+                        },
+                        character.lives,
+                    )
+                })
+                .collect();
+        player_stats.sort_by_key(|stats| stats.0);
+
+        if let Some(text) = ui_text.get_mut(tracked_stats.currency) {
+            text.text = player_stats
+                .iter()
+                .map(|stats| format!("x{}", stats.1))
+                .collect::<Vec<String>>()
+                .join(" ");
         }
 
-        for (_player, health) in (&players, &healths).join() {
-            if let Some(text) = ui_text.get_mut(tracked_stats.shields) {
-                text.text = format!("x{}", health.armor.to_string());
-            }
+        if let Some(text) = ui_text.get_mut(tracked_stats.bombs) {
+            text.text = player_stats
+                .iter()
+                .map(|stats| format!("x{}", stats.2))
+                .collect::<Vec<String>>()
+                .join(" ");
+        }
+
+        if let Some(text) = ui_text.get_mut(tracked_stats.lives) {
+            text.text = player_stats
+                .iter()
+                .map(|stats| format!("x{}", stats.5))
+                .collect::<Vec<String>>()
+                .join(" ");
+        }
+
+        if let Some(text) = ui_text.get_mut(tracked_stats.shields) {
+            text.text = player_stats
+                .iter()
+                .map(|stats| format!("x{}", stats.3))
+                .collect::<Vec<String>>()
+                .join(" ");
         }
 
-        if let Some(text) = ui_text.get_mut(tracked_stats.item_price_1) {
-            text.text = format!(
-                "${}",
-                inventory_price(
-                    &store_resource.inventory[0],
-                    &items_resource,
-                    &consumables_resource
-                )
-            );
+        if let Some(text) = ui_text.get_mut(tracked_stats.secondary_weapon) {
+            text.text = player_stats
+                .iter()
+                .map(|stats| stats.4.clone())
+                .collect::<Vec<String>>()
+                .join(" ");
         }
 
-        if let Some(text) = ui_text.get_mut(tracked_stats.item_price_2) {
-            text.text = format!(
-                "${}",
-                inventory_price(
-                    &store_resource.inventory[1],
-                    &items_resource,
-                    &consumables_resource
-                )
-            );
+        let mut player_indices: Vec<usize> = players
+            .join()
+            .map(|character| character.player_index)
+            .collect();
+        player_indices.sort_unstable();
+
+        // prices of slots the store doesn't have and prices of a closed store are left empty
+        let is_open = store_resource.is_open();
+        for (inventory_index, item_price) in tracked_stats.item_prices.iter().enumerate() {
+            if let Some(text) = ui_text.get_mut(*item_price) {
+                text.text = match store_resource.inventory.get(inventory_index) {
+                    Some(inventory_entity) if is_open => format!(
+                        "{}${}{}",
+                        highlight_marker(inventory_index, &store_resource, &player_indices),
+                        inventory_price(inventory_entity, &items_resource, &consumables_resource),
+                        lock_marker(inventory_index, &store_resource)
+                    ),
+                    _ => "".to_string(),
+                };
+            }
+
+            if let Some(ui_transform) = ui_transforms.get_mut(*item_price) {
+                ui_transform.local_y = ITEM_PRICES_TOP_Y
+                    - (ITEM_PRICES_TOP_Y - ITEM_PRICES_BOTTOM_Y)
+                        * store_resource.slot_position(inventory_index);
+            }
         }
 
-        if let Some(text) = ui_text.get_mut(tracked_stats.item_price_3) {
-            text.text = format!(
-                "${}",
-                inventory_price(
-                    &store_resource.inventory[2],
-                    &items_resource,
-                    &consumables_resource
-                )
-            );
+        // the reroll prompt names each player's reroll key and is followed by the item each
+        // player has selected for selling
+        let mut sell_offers: Vec<(usize, String)> = players
+            .join()
+            .filter_map(|character| {
+                character.item_to_sell().map(|item_type| {
+                    (
+                        character.player_index,
+                        format!(
+                            "sell {} ${}",
+                            items_resource.item_entities[item_type].name,
+                            store_resource.sell_price(item_type, &items_resource)
+                        ),
+                    )
+                })
+            })
+            .collect();
+        sell_offers.sort_by_key(|sell_offer| sell_offer.0);
+        let store_actions = sell_offers.into_iter().fold(
+            format!(
+                "reroll [{}] ${}",
+                reroll_keys(&input, &player_indices),
+                store_resource.current_reroll_price()
+            ),
+            |store_actions, (_, sell_offer)| format!("{}  {}", store_actions, sell_offer),
+        );
+
+        // details of the inspecting player's highlighted slot previewed with their stats
+        if let Some(text) = ui_text.get_mut(tracked_stats.store_details) {
+            let inspected_player = store_resource
+                .inspecting_player
+                .and_then(|player_entity| {
+                    match (players.get(player_entity), stat_sheets.get(player_entity)) {
+                        (Some(character), Some(stat_sheet)) => Some((character, stat_sheet)),
+                        _ => None,
+                    }
+                })
+                .or_else(|| {
+                    (&players, &stat_sheets)
+                        .join()
+                        .min_by_key(|(character, _)| character.player_index)
+                });
+            let highlighted_slot = inspected_player.map_or(0, |(character, _)| {
+                store_resource.highlighted_slot(character.player_index)
+            });
+            let stat_sheet = inspected_player.map(|(_, stat_sheet)| stat_sheet);
+
+            text.text = match store_resource.inventory.get(highlighted_slot) {
+                _ if !is_open => "store closed".to_string(),
+                Some(Some(spawnable_type)) => {
+                    let (name, description, modifiers) = match spawnable_type {
+                        SpawnableType::Item(item_type) => {
+                            let item_data = &items_resource.item_entities[item_type];
+                            (
+                                &item_data.name,
+                                &item_data.description,
+                                &item_modifiers_resource[item_type],
+                            )
+                        }
+                        SpawnableType::Consumable(consumable_type) => {
+                            let consumable_data =
+                                &consumables_resource.consumable_entities[consumable_type];
+                            (
+                                &consumable_data.name,
+                                &consumable_data.description,
+                                &consumable_modifiers_resource[consumable_type],
+                            )
+                        }
+                        _ => panic!("Only items and consumables can be sold in the store."),
+                    };
+
+                    let preview = stat_sheet
+                        .map(|stat_sheet| {
+                            stat_sheet
+                                .preview(
+                                    spawnable_type,
+                                    modifiers,
+                                    &stat_clamps_resource,
+                                    &buffs_resource,
+                                )
+                                .iter()
+                                .map(|(stat_type, current_value, new_value)| {
+                                    format!(
+                                        "{} {} > {}",
+                                        stat_type.label(),
+                                        round_stat(*current_value),
+                                        round_stat(*new_value)
+                                    )
+                                })
+                                .collect::<Vec<String>>()
+                                .join(", ")
+                        })
+                        .unwrap_or_default();
+
+                    format!("{}\n{}\n{}\n{}", name, description, preview, store_actions)
+                }
+                _ => store_actions,
+            };
         }
     }
 }
 
+/// Marker shown next to the price of each player's highlighted store slot,
+/// with more than one player the marker lists the players highlighting the slot
+fn highlight_marker(
+    inventory_index: usize,
+    store_resource: &StoreResource,
+    player_indices: &[usize],
+) -> String {
+    let highlighting_players: Vec<String> = player_indices
+        .iter()
+        .filter(|player_index| store_resource.highlighted_slot(**player_index) == inventory_index)
+        .map(|player_index| (player_index + 1).to_string())
+        .collect();
+
+    if highlighting_players.is_empty() {
+        "".to_string()
+    } else if player_indices.len() == 1 {
+        ">".to_string()
+    } else {
+        format!("{}>", highlighting_players.concat())
+    }
+}
+
+/// Keys bound to each player's reroll action, separated by slashes in player order
+fn reroll_keys(input: &InputHandler<StringBindings>, player_indices: &[usize]) -> String {
+    player_indices
+        .iter()
+        .filter_map(|player_index| {
+            input
+                .bindings
+                .action_bindings(&player_action(*player_index, "reroll"))
+                .next()
+                .and_then(|combination| combination.first())
+                .map(|button| match button {
+                    Button::Key(key) => format!("{:?}", key),
+                    _ => format!("{:?}", button),
+                })
+        })
+        .collect::<Vec<String>>()
+        .join("/")
+}
+
+/// Marker shown next to the price of a locked store slot
+fn lock_marker(inventory_index: usize, store_resource: &StoreResource) -> &str {
+    if store_resource.is_locked(inventory_index) {
+        "*"
+    } else {
+        ""
+    }
+}
+
+/// Round a stat value to two decimals for display
+fn round_stat(value: f32) -> f32 {
+    (value * 100.0).round() / 100.0
+}
+
 /// Get the price of an item or consumable in the store
 fn inventory_price(
     inventory_entity: &Option<SpawnableType>,
@@ -101,11 +326,7 @@ fn inventory_price(
     consumables_resource: &ReadExpect<ConsumablesResource>,
 ) -> usize {
     match inventory_entity {
-        Some(SpawnableType::Item(item_type)) => {
-            items_resource.item_entities[&item_type]
-                .item_component
-                .price
-        }
+        Some(SpawnableType::Item(item_type)) => items_resource.price(item_type),
         Some(SpawnableType::Consumable(consumable_type)) => {
             consumables_resource.consumable_entities[&consumable_type]
                 .consumable_component
@@ -1,5 +1,7 @@
 use crate::{
-    components::{BarrelRollAbilityComponent, HealthComponent, PlayerComponent},
+    components::{
+        BarrelRollAbilityComponent, HealthComponent, PlayerComponent, SpecialAbilityComponent,
+    },
     resources::{DefenseResource, SpriteSheetsResource, StoreResource},
     visual::{
         components::{StatusBarComponent, StatusType},
@@ -25,6 +27,7 @@ impl<'s> System<'s> for StatusBarSystem {
         WriteStorage<'s, StatusBarComponent>,
         ReadStorage<'s, PlayerComponent>,
         ReadStorage<'s, BarrelRollAbilityComponent>,
+        ReadStorage<'s, SpecialAbilityComponent>,
         ReadStorage<'s, HealthComponent>,
         ReadExpect<'s, StoreResource>,
         ReadExpect<'s, SpriteSheetsResource>,
@@ -40,6 +43,7 @@ impl<'s> System<'s> for StatusBarSystem {
             mut status_bars,
             players,
             barrel_roll_abilities,
+            special_abilities,
             healths,
             store_resource,
             sprite_resource,
@@ -49,19 +53,23 @@ impl<'s> System<'s> for StatusBarSystem {
     ) {
         for status_bar in (&mut status_bars).join() {
             match status_bar.status_type {
-                StatusType::Health => {
-                    for (_player, health) in (&players, &healths).join() {
-                        if let Some(status_position) =
-                            status_bar.update_units_y(health.max_value, health.value, &entities)
-                        {
-                            status_bar.status_unit_stack.push(spawn_status_unit(
-                                &entities,
-                                &sprite_resource,
-                                HEALTH_SPRITE_INDEX,
-                                status_position,
-                                &lazy_update,
-                            ));
-                        }
+                StatusType::Health(player_index) => {
+                    // the bar empties when the player is gone
+                    let (max_health, current_health) = (&players, &healths)
+                        .join()
+                        .find(|(player, _)| player.player_index == player_index)
+                        .map_or((1.0, 0.0), |(_, health)| (health.max_value, health.value));
+
+                    if let Some(status_position) =
+                        status_bar.update_units_y(max_health, current_health, &entities)
+                    {
+                        status_bar.status_unit_stack.push(spawn_status_unit(
+                            &entities,
+                            &sprite_resource,
+                            HEALTH_SPRITE_INDEX,
+                            status_position,
+                            &lazy_update,
+                        ));
                     }
                 }
 
@@ -81,22 +89,38 @@ impl<'s> System<'s> for StatusBarSystem {
                     }
                 }
 
-                StatusType::Roll => {
-                    for barrel_roll_ability in (&barrel_roll_abilities).join() {
-                        if let Some(status_position) = status_bar.update_units_x(
-                            barrel_roll_ability.execute_cooldown,
-                            barrel_roll_ability.execute_cooldown
-                                - barrel_roll_ability.execute_timer,
+                StatusType::Roll(player_index) => {
+                    // characters with a special ability show its cooldown instead of the roll,
+                    // the bar empties when the player is gone
+                    let (execute_cooldown, execute_timer) =
+                        (&players, &barrel_roll_abilities, special_abilities.maybe())
+                            .join()
+                            .find(|(player, _, _)| player.player_index == player_index)
+                            .map_or((1.0, 1.0), |(_, barrel_roll_ability, special_ability)| {
+                                match special_ability {
+                                    Some(special_ability) => (
+                                        special_ability.execute_cooldown,
+                                        special_ability.execute_timer,
+                                    ),
+                                    None => (
+                                        barrel_roll_ability.execute_cooldown,
+                                        barrel_roll_ability.execute_timer,
+                                    ),
+                                }
+                            });
+
+                    if let Some(status_position) = status_bar.update_units_x(
+                        execute_cooldown,
+                        execute_cooldown - execute_timer,
+                        &entities,
+                    ) {
+                        status_bar.status_unit_stack.push(spawn_status_unit(
                             &entities,
-                        ) {
-                            status_bar.status_unit_stack.push(spawn_status_unit(
-                                &entities,
-                                &sprite_resource,
-                                ROLL_SPRITE_INDEX,
-                                status_position,
-                                &lazy_update,
-                            ));
-                        }
+                            &sprite_resource,
+                            ROLL_SPRITE_INDEX,
+                            status_position,
+                            &lazy_update,
+                        ));
                     }
                 }
 
@@ -0,0 +1,94 @@
+use crate::{
+    components::Faction,
+    motion::components::Hitbox2DComponent,
+    resources::SpriteRenderData,
+    tools::{dot_product, project, run_sat, Vector},
+};
+use amethyst::{
+    core::{math::Vector2, transform::Transform},
+    ecs::prelude::{Component, DenseVecStorage, Entity},
+};
+use serde::{Deserialize, Serialize};
+
+/// Used for firing continuous beams
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BeamComponent {
+    /// Damage per second dealt to the first entity hit by the beam
+    pub damage_per_second: f32,
+    /// Width of the beam
+    pub width: f32,
+    /// Maximum length of the beam
+    pub max_length: f32,
+    /// Angle of the beam in radians (0.0 fires up the arena)
+    #[serde(default)]
+    pub angle: f32,
+    /// Offset of the beam origin from translation of the source
+    pub offset: Vector2<f32>,
+    /// Faction of the beam used for determining damage dealt to hit entities
+    pub faction: Faction,
+    /// Sprite stretched along the length of the beam
+    pub sprite_render_data: SpriteRenderData,
+    /// Length of the sprite before it is stretched
+    pub sprite_length: f32,
+    /// Indicates whether the beam is currently firing
+    #[serde(default)]
+    pub active: bool,
+    /// Entity displaying the beam sprite while firing
+    #[serde(skip)]
+    pub beam_entity: Option<Entity>,
+}
+
+impl Component for BeamComponent {
+    type Storage = DenseVecStorage<Self>;
+}
+
+impl BeamComponent {
+    /// Position the beam is fired from
+    pub fn origin(&self, source_transform: &Transform) -> Vector2<f32> {
+        Vector2::new(
+            source_transform.translation().x + self.offset.x,
+            source_transform.translation().y + self.offset.y,
+        )
+    }
+
+    /// Unit vector in the direction the beam is fired
+    pub fn direction(&self) -> Vector2<f32> {
+        Vector2::new(-self.angle.sin(), self.angle.cos())
+    }
+
+    /// Corners of the beam with the given length (in winding order)
+    pub fn polygon(&self, origin: Vector2<f32>, length: f32) -> [Vector; 4] {
+        let direction = self.direction();
+        let half_width = Vector2::new(direction.y, -direction.x) * (self.width / 2.0);
+        let end = origin + direction * length;
+
+        [
+            Vector(origin.x + half_width.x, origin.y + half_width.y),
+            Vector(end.x + half_width.x, end.y + half_width.y),
+            Vector(end.x - half_width.x, end.y - half_width.y),
+            Vector(origin.x - half_width.x, origin.y - half_width.y),
+        ]
+    }
+
+    /// Distance along the beam to the given hitbox, None if the hitbox is not in the beam
+    pub fn hit_distance(
+        &self,
+        origin: Vector2<f32>,
+        hitbox: &Hitbox2DComponent,
+        hitbox_transform: &Transform,
+    ) -> Option<f32> {
+        let corners = hitbox.corners(hitbox_transform);
+
+        if !run_sat(&self.polygon(origin, self.max_length), &corners) {
+            return None;
+        }
+
+        // project the hitbox onto the beam to find its nearest point along the beam
+        let direction = self.direction();
+        let axis = Vector(direction.x, direction.y);
+        let hitbox_projection = project(&corners, axis);
+        let origin_projection = dot_product(Vector(origin.x, origin.y), axis);
+
+        Some((hitbox_projection.0 - origin_projection).max(0.0))
+    }
+}
@@ -1,5 +1,11 @@
 //! Components used for weapons
 
+mod beam;
+mod secondary;
 mod weapons;
 
-pub use self::weapons::{AutoFireComponent, BlasterComponent, ManualFireComponent};
+pub use self::{
+    beam::BeamComponent,
+    secondary::{SecondaryWeapon, SecondaryWeaponComponent, SecondaryWeaponType},
+    weapons::{AutoFireComponent, BlasterComponent, ManualFireComponent},
+};
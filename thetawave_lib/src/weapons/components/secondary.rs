@@ -0,0 +1,177 @@
+use crate::weapons::components::{BeamComponent, BlasterComponent};
+use amethyst::ecs::prelude::{Component, DenseVecStorage};
+use serde::{Deserialize, Serialize};
+
+/// Types of secondary weapons
+#[derive(Clone, Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+pub enum SecondaryWeaponType {
+    MissileLauncher,
+    BombLauncher,
+    Beam,
+}
+
+impl SecondaryWeaponType {
+    /// Short name displayed on the side panel
+    pub fn label(&self) -> &str {
+        match self {
+            SecondaryWeaponType::MissileLauncher => "MSL",
+            SecondaryWeaponType::BombLauncher => "BMB",
+            SecondaryWeaponType::Beam => "BEM",
+        }
+    }
+}
+
+/// Weapon fired with its own input, cooldown and ammo
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct SecondaryWeapon {
+    /// Type of the secondary weapon
+    pub weapon_type: SecondaryWeaponType,
+    /// Blaster used to fire the secondary weapon
+    #[serde(default)]
+    pub blaster: Option<BlasterComponent>,
+    /// Beam fired while the fire action is held
+    #[serde(default)]
+    pub beam: Option<BeamComponent>,
+    /// Minimum time between shots, beams use one ammo every period while firing
+    pub period: f32,
+    /// Stores countdown time from period value
+    #[serde(default)]
+    pub timer: f32,
+    /// Remaining number of shots
+    pub ammo: usize,
+    /// Maximum number of shots that can be stored
+    pub max_ammo: usize,
+}
+
+impl SecondaryWeapon {
+    /// Returns true if the weapon is off cooldown and has ammo
+    pub fn ready(&self) -> bool {
+        self.timer <= 0.0 && self.ammo > 0
+    }
+
+    /// Add ammo up to the maximum
+    pub fn add_ammo(&mut self, amount: usize) {
+        self.ammo = (self.ammo + amount).min(self.max_ammo);
+    }
+}
+
+/// Secondary weapon slot holding every collected secondary weapon
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+pub struct SecondaryWeaponComponent {
+    /// Collected secondary weapons
+    pub weapons: Vec<SecondaryWeapon>,
+    /// Index of the equipped weapon
+    pub equipped: usize,
+    /// Maximum ammo added to every collected weapon by held items
+    #[serde(default)]
+    pub bonus_max_ammo: usize,
+}
+
+impl Component for SecondaryWeaponComponent {
+    type Storage = DenseVecStorage<Self>;
+}
+
+impl SecondaryWeaponComponent {
+    /// Currently equipped weapon
+    pub fn equipped_weapon(&self) -> Option<&SecondaryWeapon> {
+        self.weapons.get(self.equipped)
+    }
+
+    /// Currently equipped weapon (mutable)
+    pub fn equipped_weapon_mut(&mut self) -> Option<&mut SecondaryWeapon> {
+        self.weapons.get_mut(self.equipped)
+    }
+
+    /// Equip the next collected weapon
+    pub fn switch_weapon(&mut self) {
+        if !self.weapons.is_empty() {
+            self.equipped = (self.equipped + 1) % self.weapons.len();
+        }
+    }
+
+    /// Remove a collected weapon
+    pub fn lock(&mut self, weapon_type: &SecondaryWeaponType) {
+        if let Some(idx) = self
+            .weapons
+            .iter()
+            .position(|owned| owned.weapon_type == *weapon_type)
+        {
+            self.weapons.remove(idx);
+            if self.equipped > idx || self.equipped >= self.weapons.len() {
+                self.equipped = self.equipped.saturating_sub(1);
+            }
+        }
+    }
+
+    /// Add and equip a new weapon with the maximum ammo bonus, or refill the ammo of a
+    /// weapon already collected
+    pub fn unlock(&mut self, mut weapon: SecondaryWeapon) {
+        if let Some(idx) = self
+            .weapons
+            .iter()
+            .position(|owned| owned.weapon_type == weapon.weapon_type)
+        {
+            let owned = &mut self.weapons[idx];
+            owned.ammo = owned.max_ammo;
+            self.equipped = idx;
+        } else {
+            weapon.max_ammo += self.bonus_max_ammo;
+            weapon.ammo += self.bonus_max_ammo;
+            self.weapons.push(weapon);
+            self.equipped = self.weapons.len() - 1;
+        }
+    }
+
+    /// Increase the maximum ammo of every collected and future weapon
+    pub fn add_max_ammo(&mut self, amount: usize) {
+        self.bonus_max_ammo += amount;
+        for weapon in self.weapons.iter_mut() {
+            weapon.max_ammo += amount;
+            weapon.ammo += amount;
+        }
+    }
+
+    /// Decrease the maximum ammo of every collected and future weapon
+    pub fn remove_max_ammo(&mut self, amount: usize) {
+        self.bonus_max_ammo = self.bonus_max_ammo.saturating_sub(amount);
+        for weapon in self.weapons.iter_mut() {
+            weapon.max_ammo = weapon.max_ammo.saturating_sub(amount);
+            weapon.ammo = weapon.ammo.min(weapon.max_ammo);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use amethyst::config::Config;
+
+    use crate::resources::SecondaryWeaponsResource;
+
+    fn secondary_weapons() -> SecondaryWeaponsResource {
+        <SecondaryWeaponsResource as Config>::load_bytes(include_bytes!(
+            "../../../../thetawave_game/src/data_include/data/secondary_weapons.ron"
+        ))
+        .expect("failed to load secondary_weapons.ron")
+    }
+
+    #[test]
+    fn test_max_ammo_bonus_applies_to_weapons_unlocked_later() {
+        let secondary_weapons = secondary_weapons();
+        let base_max_ammo = secondary_weapons[&SecondaryWeaponType::BombLauncher].max_ammo;
+
+        // two ammo drums collected before the bomb launcher
+        let mut secondary_weapon = SecondaryWeaponComponent::default();
+        secondary_weapon.add_max_ammo(3);
+        secondary_weapon.add_max_ammo(3);
+        secondary_weapon.unlock(secondary_weapons[&SecondaryWeaponType::BombLauncher].clone());
+        assert_eq!(secondary_weapon.weapons[0].max_ammo, base_max_ammo + 6);
+
+        // selling both drums restores the launcher's own capacity
+        secondary_weapon.remove_max_ammo(3);
+        secondary_weapon.remove_max_ammo(3);
+        assert_eq!(secondary_weapon.weapons[0].max_ammo, base_max_ammo);
+        assert!(secondary_weapon.weapons[0].ammo <= base_max_ammo);
+    }
+}
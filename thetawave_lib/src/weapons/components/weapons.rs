@@ -3,7 +3,7 @@ use crate::{
         BLAST_HITBOX_DIAMETER, BLAST_Z, CRIT_BLAST_SPRITE_INDEX, ENEMY_BLAST_SPRITE_INDEX,
         PLAYER_BLAST_SPRITE_INDEX, POISON_BLAST_SPRITE_INDEX,
     },
-    motion::components::{Hitbox2DComponent, Motion2DComponent},
+    motion::components::{Hitbox2DComponent, Motion2DComponent, TargetComponent},
     resources::SpriteSheetsResource,
     spawnable::{components::BlastComponent, resources::spawn_blasts},
     weapons::BlastType,
@@ -15,7 +15,7 @@ use amethyst::{
         transform::Transform,
     },
     ecs::prelude::{Component, DenseVecStorage, Entities, LazyUpdate, ReadExpect},
-    renderer::SpriteRender,
+    renderer::{resources::Tint, SpriteRender},
 };
 
 use rand::{thread_rng, Rng};
@@ -46,6 +46,24 @@ pub struct BlasterComponent {
     pub size_multiplier: f32,
     /// Spacing between fired blasts (when count > 1)
     pub spacing: f32,
+    /// Angle in degrees between the directions of fired blasts (when count > 1)
+    #[serde(default)]
+    pub spread_angle: f32,
+    /// Number of mobs fired blasts pass through
+    #[serde(default)]
+    pub piercing: usize,
+    /// Number of times fired blasts bounce off arena borders
+    #[serde(default)]
+    pub bounces: usize,
+    /// Explosion radius of fired blasts
+    #[serde(default)]
+    pub explosion_radius: f32,
+    /// Time in seconds until fired blasts despawn (blasts despawn at the arena border if None)
+    #[serde(default)]
+    pub lifetime: Option<f32>,
+    /// Optional target component of fired blasts (homing blasts)
+    #[serde(default)]
+    pub target_component: Option<TargetComponent>,
 }
 
 impl Component for BlasterComponent {
@@ -129,6 +147,10 @@ impl BlasterComponent {
             damage: blast_damage,
             poison_damage: blast_poison_damage,
             blast_type,
+            piercing: self.piercing,
+            bounces: self.bounces,
+            explosion_radius: self.explosion_radius,
+            hit_entities: vec![],
         };
 
         let blast_spawn_x = fire_position.x
@@ -153,10 +175,13 @@ impl BlasterComponent {
         spawn_blasts(
             self.count,
             self.spacing,
+            self.spread_angle,
             blast_sprite_render,
             blast_component,
             blast_hitbox,
             blast_motion2d,
+            self.target_component.clone(),
+            self.lifetime,
             blast_transform,
             entities,
             lazy_update,
@@ -186,8 +211,48 @@ pub struct ManualFireComponent {
     pub timer: f32,
     /// Indicates whether weapon is ready to be fired
     pub ready: bool,
+    /// Time in seconds to fully charge a shot (charge mode is disabled if None)
+    #[serde(default)]
+    pub charge_time: Option<f32>,
+    /// Damage and size multiplier of a fully charged shot
+    #[serde(default)]
+    pub max_charge_multiplier: f32,
+    /// Number of mobs a fully charged shot passes through
+    #[serde(default)]
+    pub max_charge_piercing: usize,
+    /// Stores time spent charging the current shot
+    #[serde(default)]
+    pub charge_timer: f32,
+    /// Tint of the ship before the current shot started charging
+    #[serde(skip)]
+    pub tint_before_charge: Option<Tint>,
 }
 
 impl Component for ManualFireComponent {
     type Storage = DenseVecStorage<Self>;
 }
+
+impl ManualFireComponent {
+    /// Proportion of full charge of the current shot (0.0 to 1.0)
+    pub fn charge_level(&self) -> f32 {
+        match self.charge_time {
+            Some(charge_time) if charge_time > 0.0 => (self.charge_timer / charge_time).min(1.0),
+            Some(_) => 1.0,
+            None => 0.0,
+        }
+    }
+
+    /// Blaster with damage, size and piercing scaled by the charge level of the current shot
+    pub fn charged_blaster(&self, blaster: &BlasterComponent) -> BlasterComponent {
+        let charge_level = self.charge_level();
+        let multiplier = 1.0 + (self.max_charge_multiplier - 1.0) * charge_level;
+
+        let mut charged_blaster = blaster.clone();
+        charged_blaster.damage *= multiplier;
+        charged_blaster.size_multiplier *= multiplier;
+        charged_blaster.piercing +=
+            (self.max_charge_piercing as f32 * charge_level).floor() as usize;
+
+        charged_blaster
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{
+    components::{Faction, FactionComponent, HealthComponent, PlayerComponent},
+    motion::components::Hitbox2DComponent,
+    resources::{FactionsResource, SpriteSheetsResource},
+    weapons::components::BeamComponent,
+};
+use amethyst::{
+    core::{math::Vector3, timing::Time, transform::Transform, Parent},
+    ecs::prelude::{
+        Builder, Entities, Entity, Join, LazyUpdate, Read, ReadExpect, ReadStorage, System,
+        WriteStorage,
+    },
+    renderer::{SpriteRender, Transparent},
+};
+
+/// Handles ray-cast hit detection and damage of beams
+pub struct BeamSystem;
+
+impl<'s> System<'s> for BeamSystem {
+    /// Data used by the system
+    type SystemData = (
+        Entities<'s>,
+        Read<'s, Time>,
+        WriteStorage<'s, BeamComponent>,
+        WriteStorage<'s, Transform>,
+        WriteStorage<'s, HealthComponent>,
+        ReadStorage<'s, Hitbox2DComponent>,
+        ReadStorage<'s, FactionComponent>,
+        ReadStorage<'s, PlayerComponent>,
+        ReadExpect<'s, FactionsResource>,
+        ReadExpect<'s, SpriteSheetsResource>,
+        ReadExpect<'s, LazyUpdate>,
+    );
+
+    /// System game logic
+    fn run(
+        &mut self,
+        (
+            entities,
+            time,
+            mut beams,
+            mut transforms,
+            mut healths,
+            hitboxes,
+            factions,
+            players,
+            factions_resource,
+            spritesheets_resource,
+            lazy_update,
+        ): Self::SystemData,
+    ) {
+        let mut beam_transforms: Vec<(Entity, Transform)> = vec![];
+        let mut beam_damages: Vec<(Entity, f32)> = vec![];
+
+        for (source_entity, beam, source_transform) in
+            (&*entities, &mut beams, &transforms).join()
+        {
+            if !beam.active {
+                if let Some(beam_entity) = beam.beam_entity.take() {
+                    entities
+                        .delete(beam_entity)
+                        .expect("unable to delete entity");
+                }
+                continue;
+            }
+
+            let origin = beam.origin(source_transform);
+
+            // find the nearest damageable hitbox in the beam
+            let mut nearest_hit: Option<(Entity, f32, f32)> = None;
+            for (target_entity, hitbox, target_transform, _health) in
+                (&*entities, &hitboxes, &transforms, &healths).join()
+            {
+                if target_entity == source_entity {
+                    continue;
+                }
+
+                let target_faction = if players.get(target_entity).is_some() {
+                    Faction::Ally
+                } else if let Some(faction) = factions.get(target_entity) {
+                    faction.faction
+                } else {
+                    continue;
+                };
+
+                let damage_multiplier =
+                    factions_resource.damage_multiplier(&beam.faction, &target_faction);
+                if damage_multiplier <= 0.0 {
+                    continue;
+                }
+
+                if let Some(hit_distance) = beam.hit_distance(origin, hitbox, target_transform) {
+                    if nearest_hit.map_or(true, |(_, nearest_distance, _)| {
+                        hit_distance < nearest_distance
+                    }) {
+                        nearest_hit = Some((target_entity, hit_distance, damage_multiplier));
+                    }
+                }
+            }
+
+            // the beam stops at the first hit
+            let beam_length = if let Some((target_entity, hit_distance, damage_multiplier)) =
+                nearest_hit
+            {
+                beam_damages.push((
+                    target_entity,
+                    beam.damage_per_second * damage_multiplier * time.delta_seconds(),
+                ));
+                hit_distance
+            } else {
+                beam.max_length
+            };
+
+            // stretch the sprite from the origin of the beam to its end (relative to the source)
+            let mut beam_transform = Transform::default();
+            let beam_center = beam.offset + beam.direction() * (beam_length / 2.0);
+            beam_transform.set_translation_xyz(beam_center.x, beam_center.y, -0.1);
+            beam_transform.set_rotation_2d(beam.angle);
+            beam_transform.set_scale(Vector3::new(1.0, beam_length / beam.sprite_length, 1.0));
+
+            if let Some(beam_entity) = beam.beam_entity {
+                beam_transforms.push((beam_entity, beam_transform));
+            } else {
+                let beam_sprite_render = SpriteRender {
+                    sprite_sheet: spritesheets_resource.spritesheets
+                        [&beam.sprite_render_data.spritesheet]
+                        .clone(),
+                    sprite_number: beam.sprite_render_data.initial_index,
+                };
+
+                beam.beam_entity = Some(
+                    lazy_update
+                        .create_entity(&entities)
+                        .with(Parent::new(source_entity))
+                        .with(beam_transform)
+                        .with(beam_sprite_render)
+                        .with(Transparent)
+                        .build(),
+                );
+            }
+        }
+
+        for (beam_entity, beam_transform) in beam_transforms {
+            if let Some(transform) = transforms.get_mut(beam_entity) {
+                *transform = beam_transform;
+            }
+        }
+
+        for (target_entity, damage) in beam_damages {
+            if let Some(health) = healths.get_mut(target_entity) {
+                health.take_continuous_damage(damage);
+            }
+        }
+    }
+}
@@ -1,6 +1,11 @@
 //! Systems used for weapons
 
 mod autofire_system;
+mod beam_system;
 mod manualblaster_system;
+mod secondary_system;
 
-pub use self::{autofire_system::AutoFireSystem, manualblaster_system::ManualBlasterSystem};
+pub use self::{
+    autofire_system::AutoFireSystem, beam_system::BeamSystem,
+    manualblaster_system::ManualBlasterSystem, secondary_system::SecondaryWeaponSystem,
+};
@@ -0,0 +1,151 @@
+use crate::{
+    audio::Sounds,
+    components::PlayerComponent,
+    events::PlayAudioEvent,
+    motion::components::Motion2DComponent,
+    resources::SpriteSheetsResource,
+    tools::PressTracker,
+    weapons::components::{BeamComponent, SecondaryWeaponComponent},
+};
+use amethyst::{
+    core::{timing::Time, Transform},
+    ecs::prelude::{
+        Entities, Entity, Join, LazyUpdate, Read, ReadExpect, ReadStorage, System, Write,
+        WriteStorage,
+    },
+    input::{InputHandler, StringBindings},
+    shrev::EventChannel,
+};
+use std::collections::HashMap;
+
+/// Handles firing and switching of secondary weapons
+#[derive(Default)]
+pub struct SecondaryWeaponSystem {
+    /// Tracks presses of each player's switch action
+    switch_presses: PressTracker,
+    /// Entity firing each player's secondary beam, moved to the player while the beam fires
+    beam_sources: HashMap<usize, Entity>,
+}
+
+impl<'s> System<'s> for SecondaryWeaponSystem {
+    /// Data used by the system
+    type SystemData = (
+        Entities<'s>,
+        ReadStorage<'s, PlayerComponent>,
+        WriteStorage<'s, Transform>,
+        ReadStorage<'s, Motion2DComponent>,
+        WriteStorage<'s, SecondaryWeaponComponent>,
+        WriteStorage<'s, BeamComponent>,
+        Read<'s, InputHandler<StringBindings>>,
+        Read<'s, Time>,
+        ReadExpect<'s, SpriteSheetsResource>,
+        ReadExpect<'s, LazyUpdate>,
+        Write<'s, EventChannel<PlayAudioEvent>>,
+        ReadExpect<'s, Sounds>,
+    );
+
+    /// System game logic
+    fn run(
+        &mut self,
+        (
+            entities,
+            players,
+            mut transforms,
+            motion2ds,
+            mut secondary_weapons,
+            mut beams,
+            input,
+            time,
+            sprite_resource,
+            lazy_update,
+            mut play_audio_channel,
+            sounds,
+        ): Self::SystemData,
+    ) {
+        // beams fired by each player this frame along with the player's transform
+        let mut firing_beams: HashMap<usize, (BeamComponent, Transform)> = HashMap::new();
+
+        for (player, secondary_weapon, transform, motion2d) in
+            (&players, &mut secondary_weapons, &transforms, &motion2ds).join()
+        {
+            let fire_action = input
+                .action_is_down(&player.action("fire_secondary"))
+                .unwrap();
+            let switch_action = input
+                .action_is_down(&player.action("switch_secondary"))
+                .unwrap();
+
+            // only switch once per press
+            let switch_pressed = self
+                .switch_presses
+                .pressed(player.player_index, switch_action);
+
+            for weapon in secondary_weapon.weapons.iter_mut() {
+                weapon.timer = (weapon.timer - time.delta_seconds()).max(0.0);
+            }
+
+            if switch_pressed {
+                secondary_weapon.switch_weapon();
+            }
+
+            if let Some(weapon) = secondary_weapon.equipped_weapon_mut() {
+                if let Some(beam) = weapon.beam.as_ref() {
+                    // beams fire while held and use one ammo every period
+                    if fire_action && weapon.ammo > 0 {
+                        firing_beams.insert(player.player_index, (beam.clone(), transform.clone()));
+                        if weapon.timer <= 0.0 {
+                            weapon.ammo -= 1;
+                            weapon.timer = weapon.period;
+                        }
+                    }
+                } else if fire_action && weapon.ready() {
+                    if let Some(blaster) = weapon.blaster.as_ref() {
+                        blaster.fire(
+                            motion2d,
+                            transform,
+                            &entities,
+                            &sprite_resource,
+                            &lazy_update,
+                        );
+                    }
+                    weapon.ammo -= 1;
+                    weapon.timer = weapon.period;
+                    play_audio_channel.single_write(PlayAudioEvent {
+                        source: sounds.sound_effects["shotgun_cock"].clone(),
+                    });
+                }
+            }
+        }
+
+        // beams are fired by their own entity so they don't replace a player's primary beam
+        for (player_index, (beam, transform)) in firing_beams.iter() {
+            let beam_source = *self
+                .beam_sources
+                .entry(*player_index)
+                .or_insert_with(|| entities.create());
+
+            transforms
+                .insert(beam_source, transform.clone())
+                .expect("Unable to insert transform for secondary beam");
+
+            if let Some(source_beam) = beams.get_mut(beam_source) {
+                source_beam.active = true;
+            } else {
+                let mut source_beam = beam.clone();
+                source_beam.active = true;
+                beams
+                    .insert(beam_source, source_beam)
+                    .expect("Unable to insert beam for secondary beam");
+            }
+        }
+
+        // beams of players that stopped firing, switched weapons or are gone stop firing
+        for (player_index, beam_source) in self.beam_sources.iter() {
+            if !firing_beams.contains_key(player_index) {
+                if let Some(source_beam) = beams.get_mut(*beam_source) {
+                    source_beam.active = false;
+                }
+            }
+        }
+    }
+}